@@ -0,0 +1,147 @@
+use anchor_lang::prelude::Pubkey;
+use clap::{Parser, Subcommand};
+use std::{fs::File, path::PathBuf, process::ExitCode};
+use woofi_client::{parse_hash, AccountExport, ClientError, DogMetadata, PriceTable, ReceiptMetadata, TaxReceipt};
+
+/// Off-chain tooling for the Woofi program
+#[derive(Debug, Parser)]
+#[command(name = "woofi", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generate an annual donation receipt for a donor from exported accounts
+    TaxReceipt {
+        /// JSON export of program accounts (getProgramAccounts, base64 encoding)
+        #[arg(long)]
+        accounts: PathBuf,
+        /// CSV price table with `date,price` rows (price of one SOL in fiat)
+        #[arg(long)]
+        prices: PathBuf,
+        /// Donor wallet to issue the receipt for
+        #[arg(long)]
+        donor: Pubkey,
+        /// Calendar year (UTC) covered by the receipt
+        #[arg(long)]
+        year: i32,
+        /// Where to write the itemized CSV
+        #[arg(long)]
+        csv_out: PathBuf,
+        /// Where to write the text receipt
+        #[arg(long)]
+        text_out: PathBuf,
+        /// Organization name printed on the receipt
+        #[arg(long, default_value = "WooFi Rescue")]
+        organization: String,
+        /// Fiat currency of the price table
+        #[arg(long, default_value = "USD")]
+        currency: String,
+    },
+    /// Write the Metaplex JSON to upload before minting a donation's receipt NFT
+    ReceiptMetadata {
+        /// JSON export of program accounts (getProgramAccounts, base64 encoding)
+        #[arg(long)]
+        accounts: PathBuf,
+        /// Address of the donation record
+        #[arg(long)]
+        donation: Pubkey,
+        /// Image shown for the receipt
+        #[arg(long)]
+        image: String,
+        /// Where to write the metadata JSON
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Check a dog metadata document against the SHA-256 committed on-chain
+    VerifyMetadata {
+        /// The metadata JSON file
+        #[arg(long)]
+        file: PathBuf,
+        /// Expected hash in lowercase hex, as stored in `Dog.metadata_hash`
+        #[arg(long)]
+        hash: String,
+    },
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> woofi_client::Result<()> {
+    match cli.command {
+        Command::TaxReceipt {
+            accounts,
+            prices,
+            donor,
+            year,
+            csv_out,
+            text_out,
+            organization,
+            currency,
+        } => {
+            let export = AccountExport::load(accounts)?;
+            let prices = PriceTable::load(prices)?;
+            let receipt = TaxReceipt::build(&export, &prices, &donor, year)?;
+
+            receipt.write_csv(File::create(&csv_out)?)?;
+            std::fs::write(&text_out, receipt.render_text(&organization, &currency)?)?;
+
+            println!(
+                "Wrote {} donations for {} in {} to {} and {}",
+                receipt.lines.len(),
+                donor,
+                year,
+                csv_out.display(),
+                text_out.display(),
+            );
+            Ok(())
+        }
+        Command::ReceiptMetadata {
+            accounts,
+            donation,
+            image,
+            out,
+        } => {
+            let export = AccountExport::load(accounts)?;
+            let record = export
+                .donations()?
+                .into_iter()
+                .find(|record| record.pubkey == donation)
+                .ok_or_else(|| ClientError::InvalidExport(format!("donation {} not found", donation)))?;
+            let dog = export
+                .dogs()?
+                .into_iter()
+                .find(|dog| dog.pubkey == record.account.dog_id)
+                .map(|dog| dog.account.name)
+                .unwrap_or_else(|| record.account.dog_id.to_string());
+
+            let metadata = ReceiptMetadata::for_donation(&donation, &record.account, &dog, &image)?;
+            std::fs::write(&out, metadata.to_json())?;
+
+            println!("Wrote receipt metadata for {} to {}", donation, out.display());
+            Ok(())
+        }
+        Command::VerifyMetadata { file, hash } => {
+            let metadata = DogMetadata::load_verified(&file, &parse_hash(&hash)?)?;
+
+            println!(
+                "{} matches {}: {} with {} photos and {} vet notes",
+                file.display(),
+                hash,
+                metadata.name,
+                metadata.photos.len(),
+                metadata.vet_notes.len(),
+            );
+            Ok(())
+        }
+    }
+}
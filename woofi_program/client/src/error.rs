@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid account export: {0}")]
+    InvalidExport(String),
+    #[error("Account {0} could not be decoded: {1}")]
+    UndecodableAccount(String, String),
+    #[error("Invalid price table: {0}")]
+    InvalidPriceTable(String),
+    #[error("No price quoted on {0} or up to {} days before", crate::prices::MAX_PRICE_AGE_DAYS)]
+    MissingPrice(chrono::NaiveDate),
+    #[error("Fiat value of donation {0} overflows")]
+    FiatOverflow(String),
+    #[error("Invalid timestamp {0} on donation {1}")]
+    InvalidTimestamp(i64, String),
+    #[error("Invalid dog metadata: {0}")]
+    InvalidMetadata(String),
+    #[error("Metadata hash mismatch: expected {expected}, file hashes to {actual}")]
+    MetadataHashMismatch { expected: String, actual: String },
+    #[error("Receipt total overflows: {0}")]
+    TotalOverflow(&'static str),
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+}
+
+pub type Result<T> = std::result::Result<T, ClientError>;
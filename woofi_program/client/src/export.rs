@@ -0,0 +1,108 @@
+use crate::error::{ClientError, Result};
+use anchor_lang::{prelude::Pubkey, AccountDeserialize, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use std::{path::Path, str::FromStr};
+use woofi::state::{Dog, Donation};
+
+/// A single account as returned by `getProgramAccounts` or
+/// `solana account --output json` with base64 encoding.
+///
+/// Exporters may add the `signature` of the transaction that created the
+/// account, which is carried through to receipts.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExportedAccount {
+    pub pubkey: String,
+    pub account: ExportedAccountData,
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExportedAccountData {
+    /// `[data, encoding]`, only `base64` is supported
+    pub data: (String, String),
+}
+
+/// A decoded program account together with its address.
+#[derive(Debug, Clone)]
+pub struct Record<T> {
+    pub pubkey: Pubkey,
+    pub signature: Option<String>,
+    pub account: T,
+}
+
+/// Program accounts exported from a cluster.
+#[derive(Debug, Clone, Default)]
+pub struct AccountExport {
+    pub accounts: Vec<ExportedAccount>,
+}
+
+impl AccountExport {
+    /// Load a JSON array of exported accounts from `path`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Self::from_json(&json)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        let accounts = serde_json::from_str(json)
+            .map_err(|e| ClientError::InvalidExport(e.to_string()))?;
+        Ok(Self { accounts })
+    }
+
+    /// Decode every account of type `T`, skipping accounts of other types
+    pub fn decode<T: AccountDeserialize + Discriminator>(&self) -> Result<Vec<Record<T>>> {
+        self.decode_with(|data| T::try_deserialize(&mut &data[..]))
+    }
+
+    /// Decode accounts with a custom `decode`, e.g. one that also accepts older layouts.
+    ///
+    /// Accounts without `T`'s discriminator belong to other types and are skipped.
+    /// An account with the discriminator that `decode` rejects is an error, so a
+    /// layout the client does not understand is never silently left out.
+    pub fn decode_with<T: Discriminator>(
+        &self,
+        decode: impl Fn(&[u8]) -> anchor_lang::Result<T>,
+    ) -> Result<Vec<Record<T>>> {
+        let mut records = Vec::new();
+        for exported in &self.accounts {
+            let (data, encoding) = &exported.account.data;
+            if encoding != "base64" {
+                return Err(ClientError::InvalidExport(format!(
+                    "unsupported encoding {} for {}",
+                    encoding, exported.pubkey
+                )));
+            }
+
+            let pubkey = Pubkey::from_str(&exported.pubkey)
+                .map_err(|e| ClientError::InvalidExport(format!("{}: {}", exported.pubkey, e)))?;
+            let data = STANDARD
+                .decode(data)
+                .map_err(|e| ClientError::InvalidExport(format!("{}: {}", exported.pubkey, e)))?;
+
+            if !data.starts_with(T::DISCRIMINATOR) {
+                continue;
+            }
+
+            let account = decode(&data)
+                .map_err(|e| ClientError::UndecodableAccount(exported.pubkey.clone(), e.to_string()))?;
+            records.push(Record {
+                pubkey,
+                signature: exported.signature.clone(),
+                account,
+            });
+        }
+        Ok(records)
+    }
+
+    /// Donations of every layout version, so unmigrated accounts are not skipped
+    pub fn donations(&self) -> Result<Vec<Record<Donation>>> {
+        self.decode_with(Donation::try_deserialize_versioned)
+    }
+
+    /// Dogs of every layout version, so unmigrated accounts are not skipped
+    pub fn dogs(&self) -> Result<Vec<Record<Dog>>> {
+        self.decode_with(Dog::try_deserialize_versioned)
+    }
+}
@@ -0,0 +1,18 @@
+//! Off-chain tooling for Woofi program accounts.
+//!
+//! Everything in this crate works on account data exported from a cluster
+//! (e.g. a `getProgramAccounts` dump), so it can run fully offline.
+
+pub mod error;
+pub mod export;
+pub mod metadata;
+pub mod prices;
+pub mod receipt_metadata;
+pub mod tax_receipt;
+
+pub use error::*;
+pub use export::*;
+pub use metadata::*;
+pub use prices::*;
+pub use receipt_metadata::*;
+pub use tax_receipt::*;
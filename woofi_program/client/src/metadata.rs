@@ -0,0 +1,129 @@
+use crate::error::{ClientError, Result};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use woofi::state::{is_supported_uri, Dog};
+
+/// Version of the dog metadata format understood by this crate
+pub const DOG_METADATA_VERSION: u32 = 1;
+
+/// JSON schema of the document referenced by `Dog::metadata_uri`
+pub const DOG_METADATA_SCHEMA: &str = include_str!("../schema/dog-metadata.schema.json");
+
+/// Off-chain dog document, see `DOG_METADATA_SCHEMA`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DogMetadata {
+    pub version: u32,
+    pub name: String,
+    pub story: String,
+    #[serde(default)]
+    pub photos: Vec<MetadataPhoto>,
+    #[serde(default)]
+    pub vet_notes: Vec<VetNote>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MetadataPhoto {
+    pub uri: String,
+    /// Lowercase hex SHA-256 of the photo
+    pub sha256: String,
+    #[serde(default)]
+    pub caption: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VetNote {
+    /// `YYYY-MM-DD`
+    pub date: String,
+    pub note: String,
+}
+
+impl DogMetadata {
+    /// Read a metadata file and check it against the hash committed on-chain
+    pub fn load_verified(path: impl AsRef<Path>, expected_hash: &[u8; 32]) -> Result<Self> {
+        let bytes = std::fs::read(path)?;
+        let actual = sha256(&bytes);
+        if &actual != expected_hash {
+            return Err(ClientError::MetadataHashMismatch {
+                expected: to_hex(expected_hash),
+                actual: to_hex(&actual),
+            });
+        }
+        Self::from_json(&bytes)
+    }
+
+    /// Read the metadata file of `dog` and check it against the dog's hash
+    pub fn load_for_dog(path: impl AsRef<Path>, dog: &Dog) -> Result<Self> {
+        if dog.metadata_uri.is_empty() {
+            return Err(ClientError::InvalidMetadata(format!("dog {} has no metadata", dog.name)));
+        }
+        let metadata = Self::load_verified(path, &dog.metadata_hash)?;
+        if metadata.name != dog.name {
+            return Err(ClientError::InvalidMetadata(format!(
+                "document describes {} instead of {}",
+                metadata.name, dog.name
+            )));
+        }
+        Ok(metadata)
+    }
+
+    /// Parse and validate a metadata document
+    pub fn from_json(json: &[u8]) -> Result<Self> {
+        let metadata: Self =
+            serde_json::from_slice(json).map_err(|e| ClientError::InvalidMetadata(e.to_string()))?;
+        metadata.validate()?;
+        Ok(metadata)
+    }
+
+    /// Check the rules of the schema that serde does not enforce
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |reason: String| Err(ClientError::InvalidMetadata(reason));
+
+        if self.version != DOG_METADATA_VERSION {
+            return invalid(format!("unsupported version {}", self.version));
+        }
+        if self.name.is_empty() || self.story.trim().is_empty() {
+            return invalid("name and story are required".to_string());
+        }
+        for photo in &self.photos {
+            if !is_supported_uri(&photo.uri) {
+                return invalid(format!("unsupported photo URI {}", photo.uri));
+            }
+            if parse_hash(&photo.sha256).is_err() {
+                return invalid(format!("invalid sha256 for {}", photo.uri));
+            }
+        }
+        for note in &self.vet_notes {
+            if NaiveDate::parse_from_str(&note.date, "%Y-%m-%d").is_err() || note.note.trim().is_empty() {
+                return invalid(format!("invalid vet note dated {:?}", note.date));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn sha256(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Parse a lowercase hex SHA-256
+pub fn parse_hash(hex: &str) -> Result<[u8; 32]> {
+    let invalid = || ClientError::InvalidMetadata(format!("invalid hash {:?}", hex));
+    if hex.len() != 64 || !hex.bytes().all(|c| matches!(c, b'0'..=b'9' | b'a'..=b'f')) {
+        return Err(invalid());
+    }
+
+    let mut hash = [0; 32];
+    for (byte, pair) in hash.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).map_err(|_| invalid())?, 16).map_err(|_| invalid())?;
+    }
+    Ok(hash)
+}
@@ -0,0 +1,76 @@
+use crate::error::{ClientError, Result};
+use chrono::NaiveDate;
+use std::{collections::BTreeMap, path::Path};
+
+/// Number of decimal places kept for prices
+pub const PRICE_DECIMALS: u32 = 6;
+
+/// Number of days a price may predate the donation it values
+pub const MAX_PRICE_AGE_DAYS: i64 = 3;
+
+/// Daily SOL prices in a fiat currency, loaded from a `date,price` CSV file
+/// such as `2025-01-31,212.45`.
+#[derive(Debug, Clone, Default)]
+pub struct PriceTable {
+    /// Price of one SOL in millionths of the fiat unit, keyed by UTC date
+    prices: BTreeMap<NaiveDate, u64>,
+}
+
+impl PriceTable {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let csv = std::fs::read_to_string(path)?;
+        Self::from_csv(&csv)
+    }
+
+    pub fn from_csv(csv: &str) -> Result<Self> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(true)
+            .trim(csv::Trim::All)
+            .from_reader(csv.as_bytes());
+
+        let mut prices = BTreeMap::new();
+        for row in reader.records() {
+            let row = row?;
+            let (Some(date), Some(price)) = (row.get(0), row.get(1)) else {
+                return Err(ClientError::InvalidPriceTable(format!("expected date,price in {:?}", row)));
+            };
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|e| ClientError::InvalidPriceTable(format!("{}: {}", date, e)))?;
+            prices.insert(date, parse_price(price)?);
+        }
+        Ok(Self { prices })
+    }
+
+    pub fn insert(&mut self, date: NaiveDate, price_micros: u64) {
+        self.prices.insert(date, price_micros);
+    }
+
+    /// Price on `date` and the date it was quoted, falling back to the latest earlier
+    /// price if it is at most `MAX_PRICE_AGE_DAYS` old
+    pub fn price_on(&self, date: NaiveDate) -> Option<(NaiveDate, u64)> {
+        let (quoted, price) = self.prices.range(..=date).next_back()?;
+        ((date - *quoted).num_days() <= MAX_PRICE_AGE_DAYS).then_some((*quoted, *price))
+    }
+}
+
+/// Parse a decimal price such as `212.45` into millionths
+pub fn parse_price(price: &str) -> Result<u64> {
+    let invalid = || ClientError::InvalidPriceTable(format!("invalid price {:?}", price));
+
+    let (whole, fraction) = price.split_once('.').unwrap_or((price, ""));
+    if whole.is_empty() || fraction.len() > PRICE_DECIMALS as usize
+        || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let whole: u64 = whole.parse().map_err(|_| invalid())?;
+    let fraction: u64 = format!("{:0<width$}", fraction, width = PRICE_DECIMALS as usize)
+        .parse()
+        .map_err(|_| invalid())?;
+
+    whole
+        .checked_mul(10u64.pow(PRICE_DECIMALS))
+        .and_then(|whole| whole.checked_add(fraction))
+        .ok_or_else(invalid)
+}
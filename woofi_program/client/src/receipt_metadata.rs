@@ -0,0 +1,71 @@
+use crate::{
+    error::{ClientError, Result},
+    tax_receipt::format_sol,
+};
+use anchor_lang::prelude::Pubkey;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+use woofi::{state::Donation, RECEIPT_SYMBOL};
+
+/// Off-chain JSON of a receipt NFT in the Metaplex token standard, uploaded
+/// before `mint_receipt` is called with its URI.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReceiptMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub description: String,
+    pub image: String,
+    pub attributes: Vec<ReceiptAttribute>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReceiptAttribute {
+    pub trait_type: String,
+    pub value: String,
+}
+
+impl ReceiptMetadata {
+    /// Describe the receipt for the donation at `address` to the dog named `dog`.
+    /// The donor wallet is left out of anonymous donations.
+    pub fn for_donation(address: &Pubkey, donation: &Donation, dog: &str, image: &str) -> Result<Self> {
+        // Prefer the cluster clock over the donor-supplied timestamp
+        let timestamp = if donation.created_at != 0 {
+            donation.created_at
+        } else {
+            donation.timestamp
+        };
+        let created_at = DateTime::<Utc>::from_timestamp(timestamp, 0)
+            .ok_or_else(|| ClientError::InvalidTimestamp(timestamp, address.to_string()))?
+            .to_rfc3339_opts(SecondsFormat::Secs, true);
+
+        let mut attributes = vec![
+            attribute("Dog", dog.to_string()),
+            attribute("Amount (SOL)", format_sol(donation.amount)),
+            attribute("Amount (lamports)", donation.amount.to_string()),
+            attribute("Created at", created_at),
+            attribute("Donation", address.to_string()),
+        ];
+        if !donation.anonymous {
+            attributes.push(attribute("Donor", donation.donor.to_string()));
+        }
+
+        Ok(Self {
+            name: format!("Woofi receipt: {}", dog),
+            symbol: RECEIPT_SYMBOL.to_string(),
+            description: format!("Receipt for a donation of {} SOL to {}", format_sol(donation.amount), dog),
+            image: image.to_string(),
+            attributes,
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Receipt metadata always serializes")
+    }
+}
+
+fn attribute(trait_type: &str, value: String) -> ReceiptAttribute {
+    ReceiptAttribute {
+        trait_type: trait_type.to_string(),
+        value,
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{
+    error::{ClientError, Result},
+    export::AccountExport,
+    prices::{PriceTable, MAX_PRICE_AGE_DAYS, PRICE_DECIMALS},
+};
+use anchor_lang::prelude::Pubkey;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use std::{collections::HashMap, fmt::Write as _, io::Write};
+
+const LAMPORTS_PER_SOL: u128 = 1_000_000_000;
+
+/// Fiat amounts are kept in hundredths of the currency unit
+const FIAT_DECIMALS: u32 = 2;
+
+/// One donation listed on a tax receipt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiptLine {
+    pub donation: Pubkey,
+    pub date: NaiveDate,
+    pub timestamp: i64,
+    pub dog: String,
+    pub lamports: u64,
+    /// Price of one SOL in millionths of the fiat unit
+    pub price: u64,
+    /// Date the price was quoted, at most `MAX_PRICE_AGE_DAYS` before `date`
+    pub price_date: NaiveDate,
+    /// Fiat value in hundredths of the fiat unit
+    pub fiat: u64,
+    pub signature: Option<String>,
+}
+
+/// Annual donation receipt for a single donor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxReceipt {
+    pub donor: Pubkey,
+    pub year: i32,
+    pub lines: Vec<ReceiptLine>,
+}
+
+impl TaxReceipt {
+    /// Collect `donor`'s donations made during `year` (UTC) from `export`,
+    /// valued with the price table. Anonymous donations are included since the
+    /// receipt is only issued to the donor.
+    pub fn build(export: &AccountExport, prices: &PriceTable, donor: &Pubkey, year: i32) -> Result<Self> {
+        let dog_names: HashMap<Pubkey, String> = export
+            .dogs()?
+            .into_iter()
+            .map(|dog| (dog.pubkey, dog.account.name))
+            .collect();
+
+        let mut lines = Vec::new();
+        for record in export.donations()? {
+            let donation = &record.account;
+            if donation.donor != *donor {
+                continue;
+            }
+
+            // Prefer the cluster clock over the donor-supplied timestamp
+            let timestamp = if donation.created_at != 0 {
+                donation.created_at
+            } else {
+                donation.timestamp
+            };
+            let date = DateTime::<Utc>::from_timestamp(timestamp, 0)
+                .ok_or_else(|| ClientError::InvalidTimestamp(timestamp, record.pubkey.to_string()))?
+                .date_naive();
+            if date.year() != year {
+                continue;
+            }
+
+            let (price_date, price) = prices.price_on(date).ok_or(ClientError::MissingPrice(date))?;
+            let fiat = lamports_to_fiat(donation.amount, price)
+                .ok_or_else(|| ClientError::FiatOverflow(record.pubkey.to_string()))?;
+
+            lines.push(ReceiptLine {
+                donation: record.pubkey,
+                date,
+                timestamp,
+                dog: dog_names
+                    .get(&donation.dog_id)
+                    .cloned()
+                    .unwrap_or_else(|| donation.dog_id.to_string()),
+                lamports: donation.amount,
+                price,
+                price_date,
+                fiat,
+                signature: record.signature,
+            });
+        }
+
+        lines.sort_by_key(|line| (line.timestamp, line.donation));
+
+        Ok(Self {
+            donor: *donor,
+            year,
+            lines,
+        })
+    }
+
+    pub fn total_lamports(&self) -> Result<u64> {
+        self.lines
+            .iter()
+            .try_fold(0u64, |total, line| total.checked_add(line.lamports))
+            .ok_or(ClientError::TotalOverflow("lamports"))
+    }
+
+    pub fn total_fiat(&self) -> Result<u64> {
+        self.lines
+            .iter()
+            .try_fold(0u64, |total, line| total.checked_add(line.fiat))
+            .ok_or(ClientError::TotalOverflow("fiat"))
+    }
+
+    /// Write one row per donation, with a header row
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<()> {
+        let mut csv = csv::Writer::from_writer(writer);
+        csv.write_record(["date", "donation", "dog", "lamports", "sol", "price", "price_date", "fiat", "signature"])?;
+        for line in &self.lines {
+            csv.write_record([
+                line.date.to_string(),
+                line.donation.to_string(),
+                line.dog.clone(),
+                line.lamports.to_string(),
+                format_sol(line.lamports),
+                format_decimal(line.price, PRICE_DECIMALS),
+                line.price_date.to_string(),
+                format_decimal(line.fiat, FIAT_DECIMALS),
+                line.signature.clone().unwrap_or_default(),
+            ])?;
+        }
+        csv.flush()?;
+        Ok(())
+    }
+
+    /// Render a plain-text receipt laid out for printing or PDF conversion
+    pub fn render_text(&self, organization: &str, currency: &str) -> Result<String> {
+        let total_lamports = self.total_lamports()?;
+        let total_fiat = self.total_fiat()?;
+
+        let rule = "-".repeat(72);
+        let mut text = String::new();
+
+        let _ = writeln!(text, "{}", organization);
+        let _ = writeln!(text, "Official donation receipt for {}", self.year);
+        let _ = writeln!(text, "{}", rule);
+        let _ = writeln!(text, "Donor wallet: {}", self.donor);
+        let _ = writeln!(text, "Donations:    {}", self.lines.len());
+        let _ = writeln!(text, "{}", rule);
+        let _ = writeln!(text, "{:<10}  {:<24}  {:>16}  {:>14}", "Date", "Dog", "SOL", currency);
+        for line in &self.lines {
+            let _ = writeln!(
+                text,
+                "{:<10}  {:<24}  {:>16}  {:>14}",
+                line.date,
+                truncate(&line.dog, 24),
+                format_sol(line.lamports),
+                format_decimal(line.fiat, FIAT_DECIMALS),
+            );
+            let _ = writeln!(text, "            Record: {}", line.donation);
+            let _ = writeln!(
+                text,
+                "            Price: {} {} per SOL on {}",
+                format_decimal(line.price, PRICE_DECIMALS),
+                currency,
+                line.price_date,
+            );
+            if let Some(signature) = &line.signature {
+                let _ = writeln!(text, "            Transaction: {}", signature);
+            }
+        }
+        let _ = writeln!(text, "{}", rule);
+        let _ = writeln!(
+            text,
+            "{:<10}  {:<24}  {:>16}  {:>14}",
+            "Total",
+            "",
+            format_sol(total_lamports),
+            format_decimal(total_fiat, FIAT_DECIMALS),
+        );
+        let _ = writeln!(text, "{}", rule);
+        let _ = writeln!(
+            text,
+            "Values in {} use the daily SOL price on the date of each donation, or the latest",
+            currency
+        );
+        let _ = writeln!(text, "price quoted up to {} days earlier as listed.", MAX_PRICE_AGE_DAYS);
+        let _ = writeln!(text, "Each record can be verified on-chain at the listed address.");
+
+        Ok(text)
+    }
+}
+
+/// Value `lamports` at `price` millionths per SOL, in hundredths rounded half up,
+/// or `None` if the value does not fit in a `u64`
+pub fn lamports_to_fiat(lamports: u64, price: u64) -> Option<u64> {
+    let divisor = LAMPORTS_PER_SOL * 10u128.pow(PRICE_DECIMALS - FIAT_DECIMALS);
+    let fiat = (lamports as u128)
+        .checked_mul(price as u128)?
+        .checked_add(divisor / 2)?
+        / divisor;
+    u64::try_from(fiat).ok()
+}
+
+pub fn format_sol(lamports: u64) -> String {
+    format_decimal(lamports, 9)
+}
+
+fn format_decimal(value: u64, decimals: u32) -> String {
+    let scale = 10u64.pow(decimals);
+    format!("{}.{:0width$}", value / scale, value % scale, width = decimals as usize)
+}
+
+fn truncate(value: &str, max_chars: usize) -> String {
+    value.chars().take(max_chars).collect()
+}
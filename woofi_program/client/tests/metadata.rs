@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+use woofi_client::{parse_hash, sha256, to_hex, ClientError, DogMetadata, DOG_METADATA_SCHEMA};
+
+const DOCUMENT: &str = r#"{
+  "version": 1,
+  "name": "Rex",
+  "story": "Found on the street in the rain and nursed back to health.",
+  "photos": [
+    {
+      "uri": "ipfs://bafybeigdyrztrex",
+      "sha256": "0707070707070707070707070707070707070707070707070707070707070707",
+      "caption": "First day at the shelter"
+    }
+  ],
+  "vet_notes": [
+    { "date": "2025-03-01", "note": "Vaccinated against rabies" }
+  ]
+}"#;
+
+fn write_temp(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("woofi-{}-{}.json", std::process::id(), name));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn verifies_document_against_hash() {
+    let path = write_temp("valid", DOCUMENT);
+
+    let metadata = DogMetadata::load_verified(&path, &sha256(DOCUMENT.as_bytes())).unwrap();
+    assert_eq!(metadata.name, "Rex");
+    assert_eq!(metadata.photos.len(), 1);
+    assert_eq!(metadata.vet_notes[0].note, "Vaccinated against rabies");
+
+    // Any change to the file breaks the commitment
+    let result = DogMetadata::load_verified(&path, &[0; 32]);
+    assert!(matches!(result, Err(ClientError::MetadataHashMismatch { .. })));
+}
+
+#[test]
+fn rejects_documents_outside_the_schema() {
+    let unknown_field = DOCUMENT.replace("\"story\"", "\"unexpected\": true, \"story\"");
+    assert!(matches!(DogMetadata::from_json(unknown_field.as_bytes()), Err(ClientError::InvalidMetadata(_))));
+
+    let http_photo = DOCUMENT.replace("ipfs://", "http://");
+    assert!(matches!(DogMetadata::from_json(http_photo.as_bytes()), Err(ClientError::InvalidMetadata(_))));
+
+    let bad_date = DOCUMENT.replace("2025-03-01", "March 1st");
+    assert!(matches!(DogMetadata::from_json(bad_date.as_bytes()), Err(ClientError::InvalidMetadata(_))));
+
+    let newer = DOCUMENT.replace("\"version\": 1", "\"version\": 2");
+    assert!(matches!(DogMetadata::from_json(newer.as_bytes()), Err(ClientError::InvalidMetadata(_))));
+}
+
+#[test]
+fn hashes_round_trip_through_hex() {
+    let hash = sha256(DOCUMENT.as_bytes());
+    assert_eq!(parse_hash(&to_hex(&hash)).unwrap(), hash);
+    assert!(parse_hash("not hex").is_err());
+    assert!(parse_hash(&to_hex(&hash).to_uppercase()).is_err());
+}
+
+#[test]
+fn schema_is_valid_json() {
+    let schema: serde_json::Value = serde_json::from_str(DOG_METADATA_SCHEMA).unwrap();
+    assert_eq!(schema["properties"]["version"]["const"], 1);
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::Pubkey;
+use woofi::state::Donation;
+use woofi_client::ReceiptMetadata;
+
+fn donation(anonymous: bool) -> Donation {
+    Donation {
+        version: Donation::VERSION,
+        donor: Pubkey::new_unique(),
+        dog_id: Pubkey::new_unique(),
+        amount: 1_500_000_000,
+        timestamp: 1,
+        anonymous,
+        created_at: 1_740_787_200,
+        ..Default::default()
+    }
+}
+
+fn attribute<'a>(metadata: &'a ReceiptMetadata, trait_type: &str) -> Option<&'a str> {
+    metadata
+        .attributes
+        .iter()
+        .find(|attribute| attribute.trait_type == trait_type)
+        .map(|attribute| attribute.value.as_str())
+}
+
+#[test]
+fn receipt_metadata_lists_amount_and_date() {
+    let address = Pubkey::new_unique();
+    let donation = donation(false);
+
+    let metadata = ReceiptMetadata::for_donation(&address, &donation, "Rex", "https://example.com/rex.png").unwrap();
+    assert_eq!(metadata.symbol, "WOOFI");
+    assert_eq!(attribute(&metadata, "Dog"), Some("Rex"));
+    assert_eq!(attribute(&metadata, "Amount (SOL)"), Some("1.500000000"));
+    assert_eq!(attribute(&metadata, "Amount (lamports)"), Some("1500000000"));
+    assert_eq!(attribute(&metadata, "Created at"), Some("2025-03-01T00:00:00Z"));
+    assert_eq!(attribute(&metadata, "Donation"), Some(address.to_string().as_str()));
+    assert_eq!(attribute(&metadata, "Donor"), Some(donation.donor.to_string().as_str()));
+
+    let json: serde_json::Value = serde_json::from_str(&metadata.to_json()).unwrap();
+    assert_eq!(json["attributes"][1]["trait_type"], "Amount (SOL)");
+}
+
+#[test]
+fn receipt_metadata_hides_anonymous_donor() {
+    let metadata =
+        ReceiptMetadata::for_donation(&Pubkey::new_unique(), &donation(true), "Rex", "https://example.com/rex.png").unwrap();
+    assert_eq!(attribute(&metadata, "Donor"), None);
+    assert!(attribute(&metadata, "Amount (lamports)").is_some());
+}
@@ -0,0 +1,270 @@
+use anchor_lang::{prelude::Pubkey, AccountSerialize, AnchorSerialize, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::NaiveDate;
+use woofi::state::{Dog, DogLifecycle, DogProfile, DogV0, Donation, DonationV0};
+use woofi_client::{lamports_to_fiat, parse_price, AccountExport, ClientError, PriceTable, ReceiptLine, TaxReceipt};
+
+fn exported<T: AccountSerialize>(pubkey: &Pubkey, account: &T, signature: Option<&str>) -> serde_json::Value {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    serde_json::json!({
+        "pubkey": pubkey.to_string(),
+        "account": {
+            "lamports": 1_000_000,
+            "data": [STANDARD.encode(data), "base64"],
+            "owner": woofi::ID.to_string(),
+            "executable": false,
+            "rentEpoch": 0,
+        },
+        "signature": signature,
+    })
+}
+
+fn dog(name: &str) -> Dog {
+    Dog {
+        version: Dog::VERSION,
+        name: name.to_string(),
+        age: 3,
+        image_url: "https://example.com/dog.png".to_string(),
+        story: "Rescued".to_string(),
+        needs_food: true,
+        needs_toys: false,
+        needs_medical: false,
+        needs_shelter: false,
+        needs_other: String::new(),
+        total_donations: 0,
+        admin: Pubkey::new_unique(),
+        active: true,
+        owner_shelter: Pubkey::new_unique(),
+        total_withdrawn: 0,
+        profile: DogProfile::default(),
+        intake_date: 1_740_787_200,
+        birth_date: 0,
+        metadata_uri: String::new(),
+        metadata_hash: [0; 32],
+        needs: Vec::new(),
+        medical_record_count: 0,
+        update_count: 0,
+        lifecycle: DogLifecycle::Available,
+        foster: None,
+        foster_payouts: false,
+    }
+}
+
+fn donation(donor: &Pubkey, dog: &Pubkey, amount: u64, timestamp: i64) -> Donation {
+    Donation {
+        version: Donation::VERSION,
+        donor: *donor,
+        dog_id: *dog,
+        amount,
+        timestamp,
+        message: String::new(),
+        anonymous: false,
+        receipt_mint: Pubkey::default(),
+        message_hidden: false,
+        created_at: timestamp,
+        message_edited_at: 0,
+        thank_you: String::new(),
+        shelter_verified: true,
+        need_index: None,
+    }
+}
+
+#[test]
+fn test_tax_receipt_filters_and_values_donations() {
+    let donor = Pubkey::new_unique();
+    let other_donor = Pubkey::new_unique();
+    let rex = Pubkey::new_unique();
+    let (first, second, previous_year, other) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    // 2025-03-01 and 2025-06-15 00:00 UTC, plus one donation in 2024
+    let mut anonymous = donation(&donor, &rex, 500_000_000, 1_749_945_600);
+    anonymous.anonymous = true;
+    let export = serde_json::json!([
+        exported(&rex, &dog("Rex"), None),
+        exported(&second, &anonymous, Some("sig2")),
+        exported(&first, &donation(&donor, &rex, 2_000_000_000, 1_740_787_200), Some("sig1")),
+        exported(&previous_year, &donation(&donor, &rex, 1_000_000_000, 1_704_067_200), None),
+        exported(&other, &donation(&other_donor, &rex, 1_000_000_000, 1_740_787_200), None),
+    ]);
+    let export = AccountExport::from_json(&export.to_string()).unwrap();
+
+    let prices = PriceTable::from_csv("date,price\n2025-02-26,100\n2025-06-15,150.50\n").unwrap();
+
+    let receipt = TaxReceipt::build(&export, &prices, &donor, 2025).unwrap();
+
+    assert_eq!(receipt.lines.len(), 2, "Only the donor's 2025 donations are listed");
+    assert_eq!(receipt.lines[0].donation, first, "Lines are sorted by timestamp");
+    assert_eq!(receipt.lines[0].date, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+    assert_eq!(receipt.lines[0].dog, "Rex");
+    assert_eq!(receipt.lines[0].fiat, 20_000, "Falls back to the latest earlier price");
+    assert_eq!(receipt.lines[0].price_date, NaiveDate::from_ymd_opt(2025, 2, 26).unwrap());
+    assert_eq!(receipt.lines[0].signature.as_deref(), Some("sig1"));
+    assert_eq!(receipt.lines[1].fiat, 7_525, "Uses the price on the donation date");
+    assert_eq!(receipt.total_lamports().unwrap(), 2_500_000_000);
+    assert_eq!(receipt.total_fiat().unwrap(), 27_525);
+
+    let mut csv = Vec::new();
+    receipt.write_csv(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert_eq!(
+        csv.lines().next().unwrap(),
+        "date,donation,dog,lamports,sol,price,price_date,fiat,signature"
+    );
+    assert!(csv.contains(&format!("2025-03-01,{},Rex,2000000000,2.000000000,100.000000,2025-02-26,200.00,sig1", first)));
+
+    let text = receipt.render_text("WooFi Rescue", "USD").unwrap();
+    assert!(text.contains(&format!("Donor wallet: {}", donor)));
+    assert!(text.contains("275.25"));
+    assert!(text.contains("Transaction: sig2"));
+    assert!(text.contains("Price: 100.000000 USD per SOL on 2025-02-26"));
+}
+
+#[test]
+fn test_tax_receipt_includes_unmigrated_donations() {
+    let donor = Pubkey::new_unique();
+    let rex = Pubkey::new_unique();
+    let donation_key = Pubkey::new_unique();
+
+    // Donation account written before the version header, dated by its timestamp
+    let v0 = DonationV0 {
+        donor,
+        dog_id: rex,
+        amount: 1_000_000_000,
+        timestamp: 1_740_787_200,
+        message: String::new(),
+    };
+    let mut data = Donation::DISCRIMINATOR.to_vec();
+    v0.serialize(&mut data).unwrap();
+    data.resize(Donation::V0_LEN, 0);
+
+    // Dog account written before the version header
+    let dog_v0 = DogV0 {
+        name: "Rex".to_string(),
+        age: 3,
+        image_url: "https://example.com/dog.png".to_string(),
+        story: "Rescued".to_string(),
+        needs_food: true,
+        needs_toys: false,
+        needs_medical: false,
+        needs_shelter: false,
+        needs_other: String::new(),
+        total_donations: 1_000_000_000,
+        admin: Pubkey::new_unique(),
+        active: true,
+    };
+    let mut dog_data = Dog::DISCRIMINATOR.to_vec();
+    dog_v0.serialize(&mut dog_data).unwrap();
+    dog_data.resize(Dog::V0_LEN, 0);
+
+    let export = serde_json::json!([
+        {
+            "pubkey": rex.to_string(),
+            "account": { "data": [STANDARD.encode(dog_data), "base64"] },
+        },
+        {
+            "pubkey": donation_key.to_string(),
+            "account": { "data": [STANDARD.encode(data), "base64"] },
+        },
+    ]);
+    let export = AccountExport::from_json(&export.to_string()).unwrap();
+    let prices = PriceTable::from_csv("date,price\n2025-03-01,100\n").unwrap();
+
+    let receipt = TaxReceipt::build(&export, &prices, &donor, 2025).unwrap();
+    assert_eq!(receipt.lines.len(), 1, "Unmigrated donations must be listed");
+    assert_eq!(receipt.lines[0].donation, donation_key);
+    assert_eq!(receipt.lines[0].dog, "Rex", "Unmigrated dogs must be decoded");
+    assert_eq!(receipt.total_fiat().unwrap(), 10_000);
+}
+
+#[test]
+fn test_tax_receipt_missing_price() {
+    let donor = Pubkey::new_unique();
+    let dog_key = Pubkey::new_unique();
+    let export = serde_json::json!([
+        exported(&Pubkey::new_unique(), &donation(&donor, &dog_key, 1_000_000_000, 1_740_787_200), None),
+    ]);
+    let export = AccountExport::from_json(&export.to_string()).unwrap();
+    let prices = PriceTable::from_csv("date,price\n2025-12-01,100\n").unwrap();
+
+    // Should fail because there is no price on or before the donation date
+    assert!(TaxReceipt::build(&export, &prices, &donor, 2025).is_err());
+
+    // Should fail because the latest earlier price is more than a few days old
+    let prices = PriceTable::from_csv("date,price\n2025-02-25,100\n").unwrap();
+    assert!(matches!(
+        TaxReceipt::build(&export, &prices, &donor, 2025),
+        Err(ClientError::MissingPrice(date)) if date == NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+    ));
+}
+
+#[test]
+fn test_tax_receipt_rejects_undecodable_donations() {
+    let donor = Pubkey::new_unique();
+    let broken = Pubkey::new_unique();
+
+    // Donation discriminator followed by data no layout accepts
+    let mut data = Donation::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&[0xff; 16]);
+    let export = serde_json::json!([
+        exported(&Pubkey::new_unique(), &dog("Rex"), None),
+        {
+            "pubkey": broken.to_string(),
+            "account": { "data": [STANDARD.encode(data), "base64"] },
+        },
+    ]);
+    let export = AccountExport::from_json(&export.to_string()).unwrap();
+    let prices = PriceTable::from_csv("date,price\n2025-01-01,100\n").unwrap();
+
+    // Should fail instead of leaving the donation off the receipt
+    match TaxReceipt::build(&export, &prices, &donor, 2025) {
+        Err(ClientError::UndecodableAccount(pubkey, _)) => assert_eq!(pubkey, broken.to_string()),
+        other => panic!("Expected an undecodable account error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_tax_receipt_total_overflow() {
+    let line = ReceiptLine {
+        donation: Pubkey::new_unique(),
+        date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+        timestamp: 1_740_787_200,
+        dog: "Rex".to_string(),
+        lamports: u64::MAX,
+        price: 0,
+        price_date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+        fiat: u64::MAX,
+        signature: None,
+    };
+    let receipt = TaxReceipt {
+        donor: Pubkey::new_unique(),
+        year: 2025,
+        lines: vec![line.clone(), line],
+    };
+
+    // Should fail because the totals do not fit in a u64
+    assert!(matches!(receipt.total_lamports(), Err(ClientError::TotalOverflow(_))));
+    assert!(matches!(receipt.total_fiat(), Err(ClientError::TotalOverflow(_))));
+    assert!(receipt.render_text("WooFi Rescue", "USD").is_err());
+}
+
+#[test]
+fn test_price_parsing_and_conversion() {
+    assert_eq!(parse_price("212.45").unwrap(), 212_450_000);
+    assert_eq!(parse_price("7").unwrap(), 7_000_000);
+    assert!(parse_price("1.2345678").is_err());
+    assert!(parse_price("-1").is_err());
+    assert!(parse_price("abc").is_err());
+
+    // 0.333333333 SOL at 100.00 rounds to 33.33
+    assert_eq!(lamports_to_fiat(333_333_333, 100_000_000), Some(3_333));
+    // 0.005 SOL at 1.00 rounds half up to 0.01
+    assert_eq!(lamports_to_fiat(5_000_000, 1_000_000), Some(1));
+    // Should fail instead of saturating when the value does not fit in a u64
+    assert_eq!(lamports_to_fiat(u64::MAX, u64::MAX), None);
+}
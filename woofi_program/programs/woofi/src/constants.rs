@@ -2,3 +2,75 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+#[constant]
+pub const RECEIPT_SYMBOL: &str = "WOOFI";
+
+/// Metaplex limits for on-chain metadata fields
+pub const MAX_METADATA_NAME_LEN: usize = 32;
+pub const MAX_METADATA_URI_LEN: usize = 200;
+
+/// Limits of the on-chain moderation config
+pub const MAX_BANNED_SUBSTRINGS: usize = 16;
+pub const MAX_BANNED_SUBSTRING_LEN: usize = 32;
+
+/// Maximum length of donation messages and thank-you replies
+pub const MAX_MESSAGE_LEN: usize = 200;
+
+/// How long after donating a donor may edit or delete their message
+pub const MESSAGE_EDIT_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+
+/// Maximum length of a shelter name in bytes. The name is a PDA seed, and seeds
+/// are limited to 32 bytes.
+pub const MAX_SHELTER_NAME_LEN: usize = 32;
+
+/// Flags of the `Platform::paused` bitmask. Admin setters and message moderation
+/// are exempt, so the platform can still be reconfigured and abuse hidden during
+/// an incident.
+#[constant]
+pub const PAUSE_DONATIONS: u8 = 1 << 0;
+#[constant]
+pub const PAUSE_WITHDRAWALS: u8 = 1 << 1;
+#[constant]
+pub const PAUSE_DOG_MANAGEMENT: u8 = 1 << 2;
+pub const PAUSE_ALL: u8 = PAUSE_DONATIONS | PAUSE_WITHDRAWALS | PAUSE_DOG_MANAGEMENT;
+
+/// Longest delay the admin can put on shelter treasury changes
+pub const MAX_TREASURY_TIMELOCK_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Limits of the structured dog profile
+pub const MAX_BREED_LEN: usize = 32;
+pub const MAX_LOCATION_LEN: usize = 64;
+pub const MAX_DOG_WEIGHT_GRAMS: u32 = 150_000;
+
+/// Limits of a dog's needs list. Needs are never removed, so the list caps the
+/// number of needs a dog can have over its lifetime.
+pub const MAX_DOG_NEEDS: usize = 16;
+pub const MAX_NEED_DESCRIPTION_LEN: usize = 64;
+
+/// Maximum length of a medical record's procedure
+pub const MAX_PROCEDURE_LEN: usize = 64;
+
+/// Limits of a dog's progress updates
+pub const MAX_UPDATE_TITLE_LEN: usize = 64;
+pub const MAX_UPDATE_BODY_LEN: usize = 280;
+
+/// Maximum length of a foster's region
+pub const MAX_FOSTER_REGION_LEN: usize = 64;
+
+/// Limits of a dog's media gallery
+pub const MAX_DOG_MEDIA: usize = 8;
+pub const MAX_MEDIA_URI_LEN: usize = 200;
+pub const MAX_MEDIA_CAPTION_LEN: usize = 100;
+
+/// Length of an average year, used to turn birth dates into ages
+pub const SECONDS_PER_YEAR: i64 = 31_557_600;
+
+/// Oldest a dog can plausibly be at intake
+pub const MAX_DOG_AGE_YEARS: i64 = 30;
+
+/// Maximum length of a payee label
+pub const MAX_PAYEE_LABEL_LEN: usize = 50;
+
+/// Number of previous treasuries kept in each shelter's `TreasuryHistory` ring buffer
+pub const TREASURY_HISTORY_LEN: usize = 16;
@@ -21,4 +21,98 @@ pub enum WoofiError {
 	InvalidDogStory,
 	#[msg("Image URL cannot be empty")]
 	InvalidImageUrl,
+	#[msg("Receipt NFTs are not enabled on this platform")]
+	ReceiptsDisabled,
+	#[msg("Donation amount is below the receipt minimum")]
+	DonationBelowReceiptMinimum,
+	#[msg("A receipt has already been minted for this donation")]
+	ReceiptAlreadyMinted,
+	#[msg("Receipt metadata URI is too long")]
+	InvalidReceiptUri,
+	#[msg("Donation message contains banned content")]
+	MessageNotAllowed,
+	#[msg("Donation message contains too many links")]
+	TooManyUrls,
+	#[msg("Too many or too long banned substrings")]
+	InvalidModerationConfig,
+	#[msg("Message is too long")]
+	MessageTooLong,
+	#[msg("The message edit window has closed")]
+	EditWindowClosed,
+	#[msg("The message was hidden by a curator")]
+	MessageHidden,
+	#[msg("Shelter name must be between 1 and 32 bytes")]
+	InvalidShelterName,
+	#[msg("The dog does not belong to this shelter")]
+	ShelterMismatch,
+	#[msg("The shelter has not been verified")]
+	ShelterNotVerified,
+	#[msg("This action is paused")]
+	Paused,
+	#[msg("Unknown pause flags")]
+	InvalidPauseFlags,
+	#[msg("The treasury timelock has not expired yet")]
+	TimelockNotExpired,
+	#[msg("The treasury timelock can only be increased, up to 30 days")]
+	InvalidTimelock,
+	#[msg("The recipient is not a registered payee")]
+	RecipientNotAllowed,
+	#[msg("Payee label must be between 1 and 50 characters")]
+	InvalidPayeeLabel,
+	#[msg("The withdrawal exceeds the remaining allowance of this period")]
+	WithdrawalLimitExceeded,
+	#[msg("The withdrawal period must be positive when a limit is set")]
+	InvalidWithdrawPeriod,
+	#[msg("Arithmetic overflow")]
+	MathOverflow,
+	#[msg("The account already uses the current layout")]
+	AlreadyMigrated,
+	#[msg("Breed or location is too long, or the weight is implausible")]
+	InvalidDogProfile,
+	#[msg("Intake date must be in the past and after the birth date")]
+	InvalidDogDates,
+	#[msg("Media must have an https, ipfs or ar URI, a content hash and a short caption")]
+	InvalidDogMedia,
+	#[msg("Dog metadata needs an https, ipfs or ar URI together with its SHA-256")]
+	InvalidDogMetadata,
+	#[msg("Needs require a short description and a target amount")]
+	InvalidNeed,
+	#[msg("The dog's needs list is full")]
+	DogNeedsFull,
+	#[msg("No need at this index")]
+	InvalidNeedIndex,
+	#[msg("The need has already been fulfilled")]
+	NeedFulfilled,
+	#[msg("Medical records need a past date, a procedure and a document hash")]
+	InvalidMedicalRecord,
+	#[msg("The withdrawal is not a medical payment to this clinic for this dog")]
+	WithdrawalMismatch,
+	#[msg("Updates need a short title, a body or body hash, and a supported media URI if any")]
+	InvalidDogUpdate,
+	#[msg("The dog has been adopted and no longer accepts donations or applications")]
+	DogNotAvailable,
+	#[msg("Applications go from submitted to under review, then to approved or rejected")]
+	InvalidApplicationStatus,
+	#[msg("Applications need a hash of the applicant's contact details")]
+	InvalidContactHash,
+	#[msg("Fosters need a region of 1 to 64 characters and room for at least one dog")]
+	InvalidFoster,
+	#[msg("The foster has not been verified by the platform")]
+	FosterNotVerified,
+	#[msg("The foster is already caring for as many dogs as they can")]
+	FosterAtCapacity,
+	#[msg("The dog already lives with a foster")]
+	DogAlreadyFostered,
+	#[msg("The dog does not live with this foster")]
+	DogNotFostered,
+	#[msg("Dogs can only be transferred to a different shelter")]
+	InvalidDogTransfer,
+	#[msg("The dog's gallery is full")]
+	DogMediaFull,
+	#[msg("Media index out of range or not a reordering of the gallery")]
+	InvalidMediaIndex,
+	#[msg("The dog already belongs to a shelter")]
+	DogAlreadyAssigned,
+	#[msg("Transfers are only recorded when a dog moves to another shelter")]
+	InvalidSpendingCategory,
 }
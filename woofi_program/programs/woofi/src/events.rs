@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+/// A shelter treasury change was proposed and can be applied after `eta`
+#[event]
+pub struct TreasuryUpdateProposed {
+	pub shelter: Pubkey,
+	pub current_treasury: Pubkey,
+	pub pending_treasury: Pubkey,
+	pub eta: i64,
+}
+
+/// A shelter treasury was replaced
+#[event]
+pub struct TreasuryUpdated {
+	pub shelter: Pubkey,
+	pub old_treasury: Pubkey,
+	pub new_treasury: Pubkey,
+	pub timestamp: i64,
+}
+
+/// A shelter treasury holds a different balance than its recorded donations and withdrawals
+#[event]
+pub struct TreasuryDiscrepancy {
+	pub shelter: Pubkey,
+	pub treasury: Pubkey,
+	pub booked: u64,
+	pub actual: u64,
+	pub discrepancy: i64,
+}
+
+/// A progress update was posted to a dog's feed, for donors following the dog
+#[event]
+pub struct DogUpdatePosted {
+	pub dog: Pubkey,
+	pub update: Pubkey,
+	pub index: u64,
+	pub posted_at: i64,
+}
+
+/// A dog moved to another shelter together with its unspent balance. Dogs from
+/// before shelters move from the default address.
+#[event]
+pub struct DogTransferred {
+	pub dog: Pubkey,
+	pub from_shelter: Pubkey,
+	pub to_shelter: Pubkey,
+	pub balance: u64,
+}
@@ -0,0 +1,184 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct AcceptDogTransfer<'info> {
+		#[account(
+			mut,
+            constraint = admin.key() == to_shelter.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+            address = transfer.from_shelter @ error::WoofiError::ShelterMismatch
+		)]
+		pub from_shelter: Account<'info, Shelter>,
+
+		#[account(
+			mut,
+            address = transfer.to_shelter @ error::WoofiError::ShelterMismatch
+		)]
+		pub to_shelter: Account<'info, Shelter>,
+
+		#[account(
+			mut,
+            address = transfer.dog,
+            constraint = dog.owner_shelter == from_shelter.key() @ error::WoofiError::ShelterMismatch,
+            constraint = dog.foster == foster.as_ref().map(|foster| foster.wallet) @ error::WoofiError::DogNotFostered
+		)]
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"foster",
+				foster.wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub foster: Option<Account<'info, Foster>>,
+
+		#[account(
+			mut,
+            address = from_shelter.treasury @ error::WoofiError::Unauthorized
+		)]
+		/// CHECK: Treasury address is verified against from_shelter.treasury
+		pub from_treasury: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+            address = to_shelter.treasury @ error::WoofiError::Unauthorized
+		)]
+		/// CHECK: Treasury address is verified against to_shelter.treasury
+		pub to_treasury: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+            address = transfer.proposed_by @ error::WoofiError::Unauthorized
+		)]
+		/// CHECK: Proposer address is verified against transfer.proposed_by, receives the rent
+		pub proposer: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			close=proposer,
+			seeds = [
+				b"dog_transfer",
+				dog.key().as_ref(),
+			],
+			bump,
+		)]
+		pub transfer: Account<'info, DogTransfer>,
+
+		#[account(
+			init,
+			space=8 + Withdrawal::INIT_SPACE,
+			payer=admin,
+			seeds = [
+				b"withdrawal",
+				from_shelter.key().as_ref(),
+				from_shelter.withdrawal_count.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub withdrawal: Account<'info, Withdrawal>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Accept a proposed transfer, moving the dog and its unspent balance to the
+/// destination shelter.
+///
+/// The balance is booked as transferred out of the source shelter and into the
+/// destination shelter, so both still reconcile. It is neither a donation nor
+/// spending: the platform totals and the source shelter's withdrawal cap are
+/// untouched, and the source shelter's `Transfer` withdrawal record only keeps
+/// the move in its history.
+/// A foster placement belongs to the source shelter and ends with the transfer.
+///
+/// The dog account keeps its address, which remains derived from the source
+/// shelter. Donations, withdrawals, medical records and updates all point at
+/// that address, so re-seeding the dog would orphan its history; instructions
+/// check `owner_shelter` instead of the seeds.
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The destination shelter's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` from_shelter: [Shelter] The shelter currently owning the dog
+/// 3. `[writable]` to_shelter: [Shelter] The shelter the dog moves to
+/// 4. `[writable]` dog: [Dog] The dog to transfer
+/// 5. `[writable]` foster: [Foster] Optional, the dog's foster, required while the dog is fostered
+/// 6. `[writable]` from_treasury: [AccountInfo] The source shelter's treasury wallet
+/// 7. `[writable]` to_treasury: [AccountInfo] The destination shelter's treasury wallet
+/// 8. `[writable]` proposer: [AccountInfo] The wallet that proposed the transfer
+/// 9. `[writable]` transfer: [DogTransfer] The pending transfer, closed
+/// 10. `[writable]` withdrawal: [Withdrawal] The source shelter's `Transfer` record of the balance
+/// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+pub fn handler(
+	ctx: Context<AcceptDogTransfer>,
+) -> Result<()> {
+    // Refuse while dog management or withdrawals are paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT | PAUSE_WITHDRAWALS)?;
+
+    ctx.accounts.dog.check_available()?;
+
+    // Move the dog's unspent donations between treasuries
+    let balance = ctx.accounts.dog.total_donations.saturating_sub(ctx.accounts.dog.total_withdrawn);
+    if ctx.accounts.from_treasury.lamports() < balance {
+        return err!(error::WoofiError::InsufficientFunds);
+    }
+    **ctx.accounts.from_treasury.try_borrow_mut_lamports()? -= balance;
+    **ctx.accounts.to_treasury.try_borrow_mut_lamports()? += balance;
+
+    // Record the move in the source shelter's withdrawal history
+    let from_shelter = &mut ctx.accounts.from_shelter;
+    let dog = &mut ctx.accounts.dog;
+    let withdrawal = &mut ctx.accounts.withdrawal;
+    withdrawal.shelter = from_shelter.key();
+    withdrawal.dog = Some(dog.key());
+    withdrawal.amount = balance;
+    withdrawal.recipient = ctx.accounts.to_treasury.key();
+    withdrawal.timestamp = Clock::get()?.unix_timestamp;
+    withdrawal.category = SpendingCategory::Transfer;
+    withdrawal.invoice_hash = [0; 32];
+    withdrawal.index = from_shelter.withdrawal_count;
+
+    from_shelter.withdrawal_count = from_shelter.withdrawal_count.checked_add(1).ok_or(error::WoofiError::MathOverflow)?;
+    from_shelter.transferred_out = from_shelter.transferred_out.checked_add(balance).ok_or(error::WoofiError::MathOverflow)?;
+    from_shelter.dog_count = from_shelter.dog_count.saturating_sub(1);
+
+    let to_shelter = &mut ctx.accounts.to_shelter;
+    to_shelter.dog_count = to_shelter.dog_count.checked_add(1).ok_or(error::WoofiError::MathOverflow)?;
+    to_shelter.transferred_in = to_shelter.transferred_in.checked_add(balance).ok_or(error::WoofiError::MathOverflow)?;
+
+    // End the foster placement, which belonged to the source shelter
+    if let Some(foster) = &mut ctx.accounts.foster {
+        foster.dog_count = foster.dog_count.saturating_sub(1);
+    }
+    dog.foster = None;
+    dog.foster_payouts = false;
+
+    // Hand the dog over to the destination shelter
+    dog.owner_shelter = to_shelter.key();
+    dog.admin = to_shelter.admin;
+
+    emit!(DogTransferred {
+        dog: dog.key(),
+        from_shelter: from_shelter.key(),
+        to_shelter: to_shelter.key(),
+        balance,
+    });
+
+    msg!("Moved dog {} and {} lamports to shelter {}", dog.name, balance, to_shelter.name);
+
+	Ok(())
+}
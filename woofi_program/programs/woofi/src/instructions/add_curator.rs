@@ -0,0 +1,59 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		wallet: Pubkey,
+	)]
+	pub struct AddCurator<'info> {
+		#[account(
+			mut,
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			init,
+			space=72,
+			payer=admin,
+			seeds = [
+				b"curator",
+				wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub curator: Account<'info, Curator>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Grant a wallet curator rights
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` curator: [Curator] The curator record to be created
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - wallet: [Pubkey] Wallet receiving curator rights
+pub fn handler(
+	ctx: Context<AddCurator>,
+	wallet: Pubkey,
+) -> Result<()> {
+    let curator = &mut ctx.accounts.curator;
+    curator.wallet = wallet;
+    curator.added_by = ctx.accounts.admin.key();
+
+    msg!("Added curator: {}", wallet);
+
+	Ok(())
+}
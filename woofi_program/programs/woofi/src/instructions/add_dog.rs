@@ -5,18 +5,18 @@ use std::str::FromStr;
 	#[derive(Accounts)]
 	#[instruction(
 		name: String,
-		age: u8,
+		intake_date: i64,
+		birth_date: i64,
 		image_url: String,
 		story: String,
-		needs_food: bool,
-		needs_toys: bool,
-		needs_medical: bool,
-		needs_shelter: bool,
-		needs_other: String,
+		profile: DogProfile,
+		metadata_uri: String,
+		metadata_hash: [u8; 32],
 	)]
 	pub struct AddDog<'info> {
 		#[account(
-            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+			mut,
+            constraint = admin.key() == shelter.admin @ error::WoofiError::Unauthorized
         )]
 		pub admin: Signer<'info>,
 
@@ -29,12 +29,18 @@ use std::str::FromStr;
 		)]
 		pub platform: Account<'info, Platform>,
 
+		#[account(
+			mut,
+		)]
+		pub shelter: Account<'info, Shelter>,
+
 		#[account(
 			init,
-			space=1420,
+			space=3256,
 			payer=admin,
 			seeds = [
 				b"dog",
+				shelter.key().as_ref(),
 				name.as_bytes().as_ref(),
 			],
 			bump,
@@ -47,64 +53,75 @@ use std::str::FromStr;
 /// Add a new dog to the platform
 ///
 /// Accounts:
-/// 0. `[signer]` admin: [AccountInfo] 
+/// 0. `[writable, signer]` admin: [AccountInfo] The shelter's admin wallet
 /// 1. `[writable]` platform: [Platform] The platform configuration account
-/// 2. `[writable]` dog: [Dog] The dog account to be created
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 2. `[writable]` shelter: [Shelter] The shelter the dog belongs to
+/// 3. `[writable]` dog: [Dog] The dog account to be created
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] Name of the dog
-/// - age: [u8] Age of the dog in years
+/// - intake_date: [i64] When the shelter took the dog in, 0 for now
+/// - birth_date: [i64] Estimated date of birth, 0 when unknown
 /// - image_url: [String] URL to the dog's image
-/// - story: [String] The rescue story of the dog
-/// - needs_food: [bool] Whether the dog needs food
-/// - needs_toys: [bool] Whether the dog needs toys
-/// - needs_medical: [bool] Whether the dog needs medical support
-/// - needs_shelter: [bool] Whether the dog needs shelter
-/// - needs_other: [String] Other specific needs
+/// - story: [String] The rescue story of the dog, may be empty with a metadata document
+/// - profile: [DogProfile] Breed, sex, size, weight, medical status and location
+/// - metadata_uri: [String] Optional JSON document with the full story, photos and vet notes
+/// - metadata_hash: [[u8; 32]] SHA-256 of the metadata document, zero without one
 pub fn handler(
 	ctx: Context<AddDog>,
 	name: String,
-	age: u8,
+	intake_date: i64,
+	birth_date: i64,
 	image_url: String,
 	story: String,
-	needs_food: bool,
-	needs_toys: bool,
-	needs_medical: bool,
-	needs_shelter: bool,
-	needs_other: String,
+	profile: DogProfile,
+	metadata_uri: String,
+	metadata_hash: [u8; 32],
 ) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
     // Validate inputs
     if name.trim().is_empty() {
         return err!(error::WoofiError::InvalidDogName);
     }
 
-    if story.trim().is_empty() {
-        return err!(error::WoofiError::InvalidDogStory);
-    }
-
     if image_url.trim().is_empty() {
         return err!(error::WoofiError::InvalidImageUrl);
     }
 
+    profile.validate()?;
+
     // Initialize the dog account
     let dog = &mut ctx.accounts.dog;
+    dog.version = Dog::VERSION;
     dog.name = name;
-    dog.age = age;
     dog.image_url = image_url;
-    dog.story = story;
-    dog.needs_food = needs_food;
-    dog.needs_toys = needs_toys;
-    dog.needs_medical = needs_medical;
-    dog.needs_shelter = needs_shelter;
-    dog.needs_other = needs_other;
     dog.total_donations = 0;
     dog.admin = ctx.accounts.admin.key();
     dog.active = true;
+    dog.owner_shelter = ctx.accounts.shelter.key();
+    dog.total_withdrawn = 0;
+    dog.needs = Vec::new();
+    dog.sync_need_flags();
+    dog.medical_record_count = 0;
+    dog.update_count = 0;
+    dog.lifecycle = DogLifecycle::Available;
+    dog.foster = None;
+    dog.foster_payouts = false;
+    dog.profile = profile;
+    dog.set_story(story, metadata_uri, metadata_hash)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    dog.set_dates(if intake_date == 0 { now } else { intake_date }, birth_date, now)?;
+
+    // Update shelter and platform stats
+    let shelter = &mut ctx.accounts.shelter;
+    shelter.dog_count = shelter.dog_count.checked_add(1).ok_or(error::WoofiError::MathOverflow)?;
 
-    // Update platform stats
     let platform = &mut ctx.accounts.platform;
-    platform.dog_count = platform.dog_count.checked_add(1).unwrap();
+    platform.record_dog()?;
 
     msg!("Added new dog: {}", dog.name);
 	
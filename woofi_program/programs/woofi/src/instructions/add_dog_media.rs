@@ -0,0 +1,74 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		kind: MediaKind,
+		uri: String,
+		content_hash: [u8; 32],
+		caption: String,
+	)]
+	pub struct AddDogMedia<'info> {
+		#[account(
+			mut,
+            constraint = admin.key() == dog.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			init_if_needed,
+			space=2772,
+			payer=admin,
+			seeds = [
+				b"dog_media",
+				dog.key().as_ref(),
+			],
+			bump,
+		)]
+		pub dog_media: Account<'info, DogMedia>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Append an image or video to a dog's gallery
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` dog: [Dog] The dog the media shows
+/// 3. `[writable]` dog_media: [DogMedia] The dog's gallery, created on first use
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - kind: [MediaKind] Whether the media is an image or a video
+/// - uri: [String] HTTPS, IPFS or Arweave location of the file
+/// - content_hash: [[u8; 32]] SHA-256 of the file
+/// - caption: [String] Short description shown with the media
+pub fn handler(
+	ctx: Context<AddDogMedia>,
+	kind: MediaKind,
+	uri: String,
+	content_hash: [u8; 32],
+	caption: String,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    let dog_media = &mut ctx.accounts.dog_media;
+    dog_media.dog = ctx.accounts.dog.key();
+    dog_media.add(MediaEntry { kind, uri, content_hash, caption })?;
+
+    msg!("Added media {} for dog {}", dog_media.entries.len() - 1, ctx.accounts.dog.name);
+
+	Ok(())
+}
@@ -0,0 +1,131 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		date: i64,
+		procedure: String,
+		cost: u64,
+		document_hash: [u8; 32],
+	)]
+	pub struct AddMedicalRecord<'info> {
+		#[account(
+			mut,
+            constraint = curator_wallet.key() == dog.admin || Curator::is_authorized(
+                &curator_wallet.key(),
+                &platform,
+                curator.as_deref(),
+            ) @ error::WoofiError::Unauthorized
+        )]
+		pub curator_wallet: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			seeds = [
+				b"curator",
+				curator_wallet.key().as_ref(),
+			],
+			bump,
+		)]
+		pub curator: Option<Account<'info, Curator>>,
+
+		#[account(
+			mut,
+		)]
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			seeds = [
+				b"payee",
+				clinic.wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub clinic: Account<'info, Payee>,
+
+		#[account(
+			constraint = withdrawal.dog == Some(dog.key())
+				&& withdrawal.recipient == clinic.wallet
+				&& withdrawal.category == SpendingCategory::Medical @ error::WoofiError::WithdrawalMismatch,
+		)]
+		pub withdrawal: Option<Account<'info, Withdrawal>>,
+
+		#[account(
+			init,
+			space=269,
+			payer=curator_wallet,
+			seeds = [
+				b"medical_record",
+				dog.key().as_ref(),
+				dog.medical_record_count.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub medical_record: Account<'info, MedicalRecord>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Append a treatment to a dog's medical timeline
+///
+/// Accounts:
+/// 0. `[writable, signer]` curator_wallet: [AccountInfo] The dog's admin, the platform admin or a curator
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` curator: [Curator] (optional) The signer's curator record
+/// 3. `[writable]` dog: [Dog] The treated dog
+/// 4. `[]` clinic: [Payee] The registered payee that treated the dog
+/// 5. `[]` withdrawal: [Withdrawal] (optional) The medical withdrawal that paid the clinic
+/// 6. `[writable]` medical_record: [MedicalRecord] The record account to be created
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - date: [i64] Unix timestamp of the treatment
+/// - procedure: [String] What was done, e.g. "Rabies vaccination"
+/// - cost: [u64] Lamports the treatment cost
+/// - document_hash: [[u8; 32]] Content hash of the vet report or invoice
+pub fn handler(
+	ctx: Context<AddMedicalRecord>,
+	date: i64,
+	procedure: String,
+	cost: u64,
+	document_hash: [u8; 32],
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if date <= 0
+        || date > now
+        || procedure.trim().is_empty()
+        || procedure.len() > MAX_PROCEDURE_LEN
+        || document_hash == [0; 32]
+    {
+        return err!(error::WoofiError::InvalidMedicalRecord);
+    }
+
+    let dog = &mut ctx.accounts.dog;
+    let medical_record = &mut ctx.accounts.medical_record;
+    medical_record.dog = dog.key();
+    medical_record.index = dog.medical_record_count;
+    medical_record.date = date;
+    medical_record.procedure = procedure;
+    medical_record.clinic = ctx.accounts.clinic.wallet;
+    medical_record.cost = cost;
+    medical_record.withdrawal = ctx.accounts.withdrawal.as_ref().map(|withdrawal| withdrawal.key());
+    medical_record.document_hash = document_hash;
+    medical_record.recorded_by = ctx.accounts.curator_wallet.key();
+    medical_record.recorded_at = now;
+
+    dog.medical_record_count = dog.medical_record_count.checked_add(1).ok_or(error::WoofiError::MathOverflow)?;
+
+    msg!("Recorded {} for dog {}", medical_record.procedure, dog.name);
+
+	Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		category: SpendingCategory,
+		description: String,
+		target: u64,
+	)]
+	pub struct AddNeed<'info> {
+		#[account(
+            constraint = admin.key() == dog.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+		)]
+		pub dog: Account<'info, Dog>,
+	}
+
+/// Add a costed need supporters can earmark donations for
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` dog: [Dog] The dog with the need
+///
+/// Data:
+/// - category: [SpendingCategory] What the funds will be spent on
+/// - description: [String] What exactly is needed, e.g. "X-ray"
+/// - target: [u64] Lamports needed
+pub fn handler(
+	ctx: Context<AddNeed>,
+	category: SpendingCategory,
+	description: String,
+	target: u64,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    let dog = &mut ctx.accounts.dog;
+    let index = dog.add_need(category, description, target)?;
+
+    msg!("Added need {} of {} lamports for dog {}", index, target, dog.name);
+
+	Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		wallet: Pubkey,
+		label: String,
+	)]
+	pub struct AddPayee<'info> {
+		#[account(
+			mut,
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			init,
+			space=126,
+			payer=admin,
+			seeds = [
+				b"payee",
+				wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub payee: Account<'info, Payee>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Register a wallet as a payee for withdrawals
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` payee: [Payee] The payee record to be created
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - wallet: [Pubkey] Wallet of the payee
+/// - label: [String] Name of the payee, e.g. the vet clinic
+pub fn handler(
+	ctx: Context<AddPayee>,
+	wallet: Pubkey,
+	label: String,
+) -> Result<()> {
+    if label.trim().is_empty() || label.len() > MAX_PAYEE_LABEL_LEN {
+        return err!(error::WoofiError::InvalidPayeeLabel);
+    }
+
+    let payee = &mut ctx.accounts.payee;
+    payee.wallet = wallet;
+    payee.label = label;
+    payee.added_by = ctx.accounts.admin.key();
+
+    msg!("Added payee {}: {}", payee.label, wallet);
+
+	Ok(())
+}
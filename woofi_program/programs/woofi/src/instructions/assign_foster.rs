@@ -0,0 +1,70 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		allow_payouts: bool,
+	)]
+	pub struct AssignFoster<'info> {
+		#[account(
+            constraint = admin.key() == dog.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+		)]
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"foster",
+				foster.wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub foster: Account<'info, Foster>,
+	}
+
+/// Place a dog with a verified foster volunteer
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` dog: [Dog] The dog moving in with the foster
+/// 3. `[writable]` foster: [Foster] The foster taking the dog in
+///
+/// Data:
+/// - allow_payouts: [bool] Whether food and toys funds may be withdrawn straight to the foster
+pub fn handler(
+	ctx: Context<AssignFoster>,
+	allow_payouts: bool,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    let dog = &mut ctx.accounts.dog;
+    dog.check_available()?;
+    if dog.foster.is_some() {
+        return err!(error::WoofiError::DogAlreadyFostered);
+    }
+
+    let foster = &mut ctx.accounts.foster;
+    foster.take_dog()?;
+
+    dog.foster = Some(foster.wallet);
+    dog.foster_payouts = allow_payouts;
+
+    msg!("Dog {} is now fostered by {}", dog.name, foster.wallet);
+
+	Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct AssignLegacyDog<'info> {
+		#[account(
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+		)]
+		pub shelter: Account<'info, Shelter>,
+
+		#[account(
+			mut,
+            constraint = dog.owner_shelter == Pubkey::default() @ error::WoofiError::DogAlreadyAssigned
+		)]
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			mut,
+            address = platform.treasury @ error::WoofiError::Unauthorized
+		)]
+		/// CHECK: Treasury address is verified against platform.treasury
+		pub legacy_treasury: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+            address = shelter.treasury @ error::WoofiError::Unauthorized
+		)]
+		/// CHECK: Treasury address is verified against shelter.treasury
+		pub treasury: UncheckedAccount<'info>,
+	}
+
+/// Hand a dog from before shelters to a shelter, together with its unspent balance
+///
+/// Dogs migrated from the original layout belong to no shelter, and their
+/// donations were paid into the platform treasury. The balance moves to the
+/// shelter treasury and is booked as the shelter's donations so the shelter
+/// still reconciles; the platform totals already count it.
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The platform admin
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter taking the dog in
+/// 3. `[writable]` dog: [Dog] The migrated dog, not yet assigned to a shelter
+/// 4. `[writable]` legacy_treasury: [AccountInfo] The platform treasury holding the dog's donations
+/// 5. `[writable]` treasury: [AccountInfo] The shelter's treasury wallet
+pub fn handler(
+	ctx: Context<AssignLegacyDog>,
+) -> Result<()> {
+    // Refuse while dog management or withdrawals are paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT | PAUSE_WITHDRAWALS)?;
+
+    // Move the dog's unspent donations out of the platform treasury
+    let balance = ctx.accounts.dog.total_donations.saturating_sub(ctx.accounts.dog.total_withdrawn);
+    if ctx.accounts.legacy_treasury.lamports() < balance {
+        return err!(error::WoofiError::InsufficientFunds);
+    }
+    **ctx.accounts.legacy_treasury.try_borrow_mut_lamports()? -= balance;
+    **ctx.accounts.treasury.try_borrow_mut_lamports()? += balance;
+
+    let shelter = &mut ctx.accounts.shelter;
+    shelter.dog_count = shelter.dog_count.checked_add(1).ok_or(error::WoofiError::MathOverflow)?;
+    shelter.total_donations = shelter.total_donations.checked_add(balance).ok_or(error::WoofiError::MathOverflow)?;
+
+    let dog = &mut ctx.accounts.dog;
+    dog.owner_shelter = shelter.key();
+    dog.admin = shelter.admin;
+
+    emit!(DogTransferred {
+        dog: dog.key(),
+        from_shelter: Pubkey::default(),
+        to_shelter: shelter.key(),
+        balance,
+    });
+
+    msg!("Assigned dog {} and {} lamports to shelter {}", dog.name, balance, shelter.name);
+
+	Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct CancelDogTransfer<'info> {
+		#[account(
+            constraint = admin.key() == transfer.proposed_by || admin.key() == to_shelter.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+            address = transfer.to_shelter @ error::WoofiError::ShelterMismatch
+		)]
+		pub to_shelter: Account<'info, Shelter>,
+
+		#[account(
+			mut,
+            address = transfer.proposed_by @ error::WoofiError::Unauthorized
+		)]
+		/// CHECK: Proposer address is verified against transfer.proposed_by, receives the rent
+		pub proposer: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			close=proposer,
+			seeds = [
+				b"dog_transfer",
+				transfer.dog.as_ref(),
+			],
+			bump,
+		)]
+		pub transfer: Account<'info, DogTransfer>,
+	}
+
+/// Withdraw or decline a proposed transfer, by either shelter's admin
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The proposer or the destination shelter's admin
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` to_shelter: [Shelter] The shelter the dog would have moved to
+/// 3. `[writable]` proposer: [AccountInfo] The wallet that proposed the transfer
+/// 4. `[writable]` transfer: [DogTransfer] The pending transfer, closed
+pub fn handler(
+	ctx: Context<CancelDogTransfer>,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    msg!("Cancelled transfer of dog {}", ctx.accounts.transfer.dog);
+
+	Ok(())
+}
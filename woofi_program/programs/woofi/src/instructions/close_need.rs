@@ -0,0 +1,51 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		index: u8,
+	)]
+	pub struct CloseNeed<'info> {
+		#[account(
+            constraint = admin.key() == dog.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+		)]
+		pub dog: Account<'info, Dog>,
+	}
+
+/// Mark a need as fulfilled before its target is reached, e.g. when it was paid
+/// for by other means. Further donations cannot be earmarked for it.
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` dog: [Dog] The dog with the need
+///
+/// Data:
+/// - index: [u8] Position of the need in the dog's list
+pub fn handler(
+	ctx: Context<CloseNeed>,
+	index: u8,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    let dog = &mut ctx.accounts.dog;
+    dog.close_need(index)?;
+
+    msg!("Closed need {} for dog {}", index, dog.name);
+
+	Ok(())
+}
@@ -7,6 +7,8 @@ use std::str::FromStr;
 		amount: u64,
 		message: String,
 		timestamp: i64,
+		anonymous: bool,
+		need_index: Option<u8>,
 	)]
 	pub struct Donate<'info> {
 		#[account(
@@ -23,6 +25,12 @@ use std::str::FromStr;
 		)]
 		pub platform: Account<'info, Platform>,
 
+		#[account(
+			mut,
+            address = dog.owner_shelter @ error::WoofiError::ShelterMismatch
+		)]
+		pub shelter: Account<'info, Shelter>,
+
 		#[account(
 			mut,
 		)]
@@ -30,7 +38,7 @@ use std::str::FromStr;
 
 		#[account(
 			init,
-			space=292,
+			space=550,
 			payer=donor,
 			seeds = [
 				b"donation",
@@ -43,11 +51,20 @@ use std::str::FromStr;
 
 		#[account(
 			mut,
-            address = platform.treasury,
+            address = shelter.treasury,
 		)]
-		/// CHECK: Treasury address is verified against platform.treasury
+		/// CHECK: Treasury address is verified against shelter.treasury
 		pub treasury: UncheckedAccount<'info>,
 
+		#[account(
+			seeds = [
+				b"moderation",
+			],
+			bump,
+		)]
+		/// CHECK: Moderation config PDA, empty until the admin sets it
+		pub moderation: UncheckedAccount<'info>,
+
 		pub system_program: Program<'info, System>,
 	}
 
@@ -56,26 +73,49 @@ use std::str::FromStr;
 /// Accounts:
 /// 0. `[writable, signer]` donor: [AccountInfo] 
 /// 1. `[writable]` platform: [Platform] The platform configuration account
-/// 2. `[writable]` dog: [Dog] The dog receiving the donation
-/// 3. `[writable]` donation: [Donation] The donation record account
-/// 4. `[writable]` treasury: [AccountInfo] Treasury wallet to receive the donation
-/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 2. `[writable]` shelter: [Shelter] The shelter caring for the dog
+/// 3. `[writable]` dog: [Dog] The dog receiving the donation
+/// 4. `[writable]` donation: [Donation] The donation record account
+/// 5. `[writable]` treasury: [AccountInfo] The shelter's treasury wallet to receive the donation
+/// 6. `[]` moderation: [ModerationConfig] The moderation rules for donation messages, if set
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - amount: [u64] Amount of SOL to donate
 /// - message: [String] Optional message from the donor
 /// - timestamp: [i64] Unix timestamp of when the donation was made
+/// - anonymous: [bool] Hide the donor's identity from public listings
+/// - need_index: [Option<u8>] Need of the dog to earmark the donation for
 pub fn handler(
 	ctx: Context<Donate>,
 	amount: u64,
 	message: String,
 	timestamp: i64,
+	anonymous: bool,
+	need_index: Option<u8>,
 ) -> Result<()> {
+    // Refuse while donations are paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DONATIONS)?;
+
+    // Refuse once the dog has been adopted
+    ctx.accounts.dog.check_available()?;
+
     // Validate the donation amount
     if amount == 0 {
         return err!(error::WoofiError::InvalidAmount);
     }
 
+    // Enforce the platform's verification policy
+    let shelter_verified = ctx.accounts.shelter.verified;
+    if ctx.accounts.platform.require_verified_shelters && !shelter_verified {
+        return err!(error::WoofiError::ShelterNotVerified);
+    }
+
+    // Enforce the moderation rules on the public message
+    if let Some(moderation) = ModerationConfig::load(&ctx.accounts.moderation)? {
+        moderation.check_message(&message)?;
+    }
+
     // Transfer SOL from donor to treasury
     let transfer_instruction = anchor_lang::system_program::transfer(
         ctx.accounts.donor.to_account_info().key,
@@ -94,22 +134,42 @@ pub fn handler(
 
     // Update donation record
     let donation = &mut ctx.accounts.donation;
+    donation.version = Donation::VERSION;
     donation.donor = ctx.accounts.donor.key();
     donation.dog_id = ctx.accounts.dog.key();
     donation.amount = amount;
     donation.timestamp = timestamp;
     donation.message = message;
+    donation.anonymous = anonymous;
+    donation.receipt_mint = Pubkey::default();
+    donation.message_hidden = false;
+    donation.created_at = Clock::get()?.unix_timestamp;
+    donation.message_edited_at = 0;
+    donation.thank_you = String::new();
+    donation.shelter_verified = shelter_verified;
+    donation.need_index = need_index;
 
     // Update dog's total donations
     let dog = &mut ctx.accounts.dog;
-    dog.total_donations = dog.total_donations.checked_add(amount).unwrap();
+    dog.total_donations = dog.total_donations.checked_add(amount).ok_or(error::WoofiError::MathOverflow)?;
+    if let Some(need_index) = need_index {
+        dog.record_need_donation(need_index, amount)?;
+    }
+
+    // Update shelter's total donations and donation count
+    let shelter = &mut ctx.accounts.shelter;
+    shelter.total_donations = shelter.total_donations.checked_add(amount).ok_or(error::WoofiError::MathOverflow)?;
+    shelter.donation_count = shelter.donation_count.checked_add(1).ok_or(error::WoofiError::MathOverflow)?;
 
     // Update platform's total donations and donation count
     let platform = &mut ctx.accounts.platform;
-    platform.total_donations = platform.total_donations.checked_add(amount).unwrap();
-    platform.donation_count = platform.donation_count.checked_add(1).unwrap();
+    platform.record_donation(amount)?;
 
-    msg!("Donation of {} lamports made to dog: {}", amount, dog.name);
+    if anonymous {
+        msg!("Anonymous donation of {} lamports made to dog: {}", amount, dog.name);
+    } else {
+        msg!("Donation of {} lamports made to dog: {}", amount, dog.name);
+    }
 	
 	Ok(())
 }
\ No newline at end of file
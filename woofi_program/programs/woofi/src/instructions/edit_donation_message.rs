@@ -0,0 +1,80 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		message: String,
+	)]
+	pub struct EditDonationMessage<'info> {
+		#[account(
+            constraint = donor.key() == donation.donor @ error::WoofiError::Unauthorized
+        )]
+		pub donor: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+		)]
+		pub donation: Account<'info, Donation>,
+
+		#[account(
+			seeds = [
+				b"moderation",
+			],
+			bump,
+		)]
+		/// CHECK: Moderation config PDA, empty until the admin sets it
+		pub moderation: UncheckedAccount<'info>,
+	}
+
+/// Edit or delete the message of a donation within the edit window
+///
+/// Accounts:
+/// 0. `[signer]` donor: [AccountInfo] The donor who made the donation
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` donation: [Donation] The donation whose message is edited
+/// 3. `[]` moderation: [ModerationConfig] The moderation rules for donation messages, if set
+///
+/// Data:
+/// - message: [String] The new message, empty to delete it
+pub fn handler(
+	ctx: Context<EditDonationMessage>,
+	message: String,
+) -> Result<()> {
+    // Refuse while donations are paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DONATIONS)?;
+
+    let donation = &mut ctx.accounts.donation;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validate the edit is still allowed
+    if now > donation.created_at.saturating_add(MESSAGE_EDIT_WINDOW_SECONDS) {
+        return err!(error::WoofiError::EditWindowClosed);
+    }
+
+    if donation.message_hidden {
+        return err!(error::WoofiError::MessageHidden);
+    }
+
+    if message.len() > MAX_MESSAGE_LEN {
+        return err!(error::WoofiError::MessageTooLong);
+    }
+
+    if let Some(moderation) = ModerationConfig::load(&ctx.accounts.moderation)? {
+        moderation.check_message(&message)?;
+    }
+
+    donation.message = message;
+    donation.message_edited_at = now;
+
+    msg!("Edited message of donation: {}", donation.key());
+
+	Ok(())
+}
@@ -0,0 +1,59 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct EndFoster<'info> {
+		#[account(
+            constraint = signer.key() == dog.admin || signer.key() == foster.wallet @ error::WoofiError::Unauthorized
+        )]
+		pub signer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+            constraint = dog.foster == Some(foster.wallet) @ error::WoofiError::DogNotFostered
+		)]
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"foster",
+				foster.wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub foster: Account<'info, Foster>,
+	}
+
+/// End a dog's stay with its foster, by the dog's admin or the foster themselves
+///
+/// Accounts:
+/// 0. `[signer]` signer: [AccountInfo] The dog's admin or the foster's wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` dog: [Dog] The dog leaving the foster
+/// 3. `[writable]` foster: [Foster] The dog's current foster
+pub fn handler(
+	ctx: Context<EndFoster>,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    let dog = &mut ctx.accounts.dog;
+    dog.foster = None;
+    dog.foster_payouts = false;
+
+    let foster = &mut ctx.accounts.foster;
+    foster.dog_count = foster.dog_count.saturating_sub(1);
+
+    msg!("Dog {} left foster {}", dog.name, foster.wallet);
+
+	Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct HideDonationMessage<'info> {
+		#[account(
+            constraint = Curator::is_authorized(
+                &moderator.key(),
+                &platform,
+                curator.as_deref(),
+            ) @ error::WoofiError::Unauthorized
+        )]
+		pub moderator: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			seeds = [
+				b"curator",
+				moderator.key().as_ref(),
+			],
+			bump,
+		)]
+		pub curator: Option<Account<'info, Curator>>,
+
+		#[account(
+			mut,
+		)]
+		pub donation: Account<'info, Donation>,
+	}
+
+/// Hide a donation message from public pages
+///
+/// Not affected by the pause flags, so abusive messages can be hidden during an incident.
+///
+/// Accounts:
+/// 0. `[signer]` moderator: [AccountInfo] The platform admin or a curator
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` curator: [Curator] (optional) The moderator's curator record
+/// 3. `[writable]` donation: [Donation] The donation whose message is hidden
+pub fn handler(
+	ctx: Context<HideDonationMessage>,
+) -> Result<()> {
+    // Clear the message so it is no longer readable on-chain
+    let donation = &mut ctx.accounts.donation;
+    donation.message = String::new();
+    donation.message_hidden = true;
+
+    msg!("Hid message of donation: {}", donation.key());
+
+	Ok(())
+}
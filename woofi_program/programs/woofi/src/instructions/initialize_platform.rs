@@ -16,7 +16,7 @@ pub struct InitializePlatform<'info> {
     /// This is a PDA with seeds ["platform"]
     #[account(
         init,
-        space = 8 + std::mem::size_of::<Platform>(),
+        space = 8 + Platform::INIT_SPACE,
         payer = admin,
         seeds = [
             b"platform",
@@ -50,15 +50,11 @@ pub fn handler(
     treasury: Pubkey,
 ) -> Result<()> {
     // Validate treasury is not a system address, program, or zero address
-    require!(
-        treasury != System::id() && 
-        treasury != ctx.program_id &&
-        treasury != Pubkey::default(),
-        WoofiError::Unauthorized
-    );
+    validate_treasury(&treasury, ctx.program_id)?;
 
     // Initialize the platform account with default values
     let platform = &mut ctx.accounts.platform;
+    platform.version = Platform::VERSION;
     
     // Set admin to the signer of this transaction
     platform.admin = ctx.accounts.admin.key();
@@ -70,6 +66,20 @@ pub fn handler(
     platform.total_donations = 0;
     platform.dog_count = 0;
     platform.donation_count = 0;
+    platform.shelter_count = 0;
+    platform.require_verified_shelters = false;
+    platform.paused = 0;
+    platform.guardian = Pubkey::default();
+    platform.treasury_timelock_secs = 0;
+    platform.require_registered_payees = false;
+    platform.max_withdraw_per_period = 0;
+    platform.withdraw_period_secs = 0;
+    platform.total_withdrawn = 0;
+    platform.total_fees = 0;
+
+    // Receipt NFTs stay disabled until the admin opts in
+    platform.receipts_enabled = false;
+    platform.receipt_min_amount = 0;
 
     // Log initialization details
     msg!("🐶 WooFi Platform initialized successfully");
@@ -77,4 +87,16 @@ pub fn handler(
     msg!("Treasury: {}", platform.treasury);
     
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Reject treasuries that cannot hold funds: the System program, this program, or the zero address
+pub fn validate_treasury(treasury: &Pubkey, program_id: &Pubkey) -> Result<()> {
+    require!(
+        *treasury != System::id() && 
+        treasury != program_id &&
+        *treasury != Pubkey::default(),
+        error::WoofiError::Unauthorized
+    );
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct MigrateDog<'info> {
+		#[account(
+			mut,
+		)]
+		pub payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+			owner = crate::ID,
+		)]
+		/// CHECK: May still be in an older layout, so it is decoded by hand
+		pub dog: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Migrate a dog account to the current layout version
+///
+/// Anyone may migrate a dog since the stored data is preserved; the payer covers
+/// the additional rent. Dogs from before shelters belong to no shelter until the
+/// platform admin assigns them with `assign_legacy_dog`.
+///
+/// Accounts:
+/// 0. `[writable, signer]` payer: [AccountInfo] Pays for the larger account
+/// 1. `[]` platform: [Platform] The platform configuration account, migrated first
+/// 2. `[writable]` dog: [Dog] The dog account to migrate
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account reallocation
+pub fn handler(
+	ctx: Context<MigrateDog>,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    let dog_info = ctx.accounts.dog.to_account_info();
+    let dog = {
+        let data = dog_info.try_borrow_data()?;
+        if !Dog::is_outdated(&data) {
+            return err!(error::WoofiError::AlreadyMigrated);
+        }
+        Dog::try_deserialize_versioned(&data)?
+    };
+
+    migrate_platform::rewrite_account(
+        &dog_info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        Dog::LEN,
+        &dog,
+    )?;
+
+    msg!("Migrated dog {} to version {}", dog.name, dog.version);
+
+	Ok(())
+}
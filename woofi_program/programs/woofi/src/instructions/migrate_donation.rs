@@ -0,0 +1,65 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct MigrateDonation<'info> {
+		#[account(
+			mut,
+		)]
+		pub payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+			owner = crate::ID,
+		)]
+		/// CHECK: May still be in an older layout, so it is decoded by hand
+		pub donation: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Migrate a donation account to the current layout version
+///
+/// Anyone may migrate a donation since the stored data is preserved; the payer covers
+/// the additional rent.
+///
+/// Accounts:
+/// 0. `[writable, signer]` payer: [AccountInfo] Pays for the larger account
+/// 1. `[]` platform: [Platform] The platform configuration account, migrated first
+/// 2. `[writable]` donation: [Donation] The donation account to migrate
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account reallocation
+pub fn handler(
+	ctx: Context<MigrateDonation>,
+) -> Result<()> {
+    // Refuse while donations are paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DONATIONS)?;
+
+    let donation_info = ctx.accounts.donation.to_account_info();
+    let donation = {
+        let data = donation_info.try_borrow_data()?;
+        if !Donation::is_outdated(&data) {
+            return err!(error::WoofiError::AlreadyMigrated);
+        }
+        Donation::try_deserialize_versioned(&data)?
+    };
+
+    migrate_platform::rewrite_account(
+        &donation_info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        Donation::LEN,
+        &donation,
+    )?;
+
+    msg!("Migrated donation to version {}", donation.version);
+
+	Ok(())
+}
@@ -0,0 +1,94 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+	#[derive(Accounts)]
+	pub struct MigratePlatform<'info> {
+		#[account(
+			mut,
+		)]
+		pub admin: Signer<'info>,
+
+		#[account(
+			mut,
+			owner = crate::ID,
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		/// CHECK: May still be in an older layout, so it is decoded by hand
+		pub platform: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Migrate the platform account to the current layout version
+///
+/// Layouts before the version header are detected by their allocated size. The
+/// account is grown in place and the admin pays the additional rent.
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The platform admin
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+/// 2. `[]` system_program: [AccountInfo] Auto-generated, for account reallocation
+pub fn handler(
+	ctx: Context<MigratePlatform>,
+) -> Result<()> {
+    let platform_info = ctx.accounts.platform.to_account_info();
+    let platform = {
+        let data = platform_info.try_borrow_data()?;
+        if !Platform::is_outdated(&data) {
+            return err!(error::WoofiError::AlreadyMigrated);
+        }
+        Platform::try_deserialize_versioned(&data)?
+    };
+
+    if ctx.accounts.admin.key() != platform.admin {
+        return err!(error::WoofiError::Unauthorized);
+    }
+
+    rewrite_account(
+        &platform_info,
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+        8 + Platform::INIT_SPACE,
+        &platform,
+    )?;
+
+    msg!("Migrated platform to version {}", platform.version);
+
+	Ok(())
+}
+
+/// Grow `account` to `new_len`, topping up rent from `payer`, and write `upgraded` into it
+pub fn rewrite_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+    upgraded: &T,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(new_len);
+    let lamports = account.lamports();
+    if lamports < rent {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            rent - lamports,
+        )?;
+    }
+
+    if account.data_len() < new_len {
+        account.resize(new_len)?;
+    }
+
+    upgraded.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,246 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{native_token::LAMPORTS_PER_SOL, program_pack::Pack};
+use anchor_spl::token::{
+    self, spl_token, InitializeAccount3, InitializeMint2, MintTo, SetAuthority, Token,
+};
+use mpl_token_metadata::{
+    instructions::CreateMetadataAccountV3CpiBuilder, types::DataV2,
+};
+
+	#[derive(Accounts)]
+	#[instruction(
+		uri: String,
+	)]
+	pub struct MintReceipt<'info> {
+		#[account(
+			mut,
+		)]
+		pub donor: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+            address = donation.dog_id @ error::WoofiError::DogNotFound
+        )]
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			mut,
+            constraint = donation.donor == donor.key() @ error::WoofiError::Unauthorized
+		)]
+		pub donation: Account<'info, Donation>,
+
+		#[account(
+			init,
+			space=spl_token::state::Mint::LEN,
+			owner=token_program.key(),
+			payer=donor,
+			seeds = [
+				b"receipt_mint",
+				donation.key().as_ref(),
+			],
+			bump,
+		)]
+		/// CHECK: Created here and initialized as a mint by the token program
+		pub mint: UncheckedAccount<'info>,
+
+		#[account(
+			init,
+			space=spl_token::state::Account::LEN,
+			owner=token_program.key(),
+			payer=donor,
+			seeds = [
+				b"receipt_token",
+				donation.key().as_ref(),
+			],
+			bump,
+		)]
+		/// CHECK: Created here and initialized as the donor's token account by the token program
+		pub receipt_token_account: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"metadata",
+				token_metadata_program.key().as_ref(),
+				mint.key().as_ref(),
+			],
+			seeds::program = token_metadata_program.key(),
+			bump,
+		)]
+		/// CHECK: Metadata PDA is verified by seeds and initialized by the token metadata program
+		pub metadata: UncheckedAccount<'info>,
+
+		pub token_program: Program<'info, Token>,
+
+		#[account(
+			address = mpl_token_metadata::ID,
+		)]
+		/// CHECK: Address is verified against the Metaplex Token Metadata program id
+		pub token_metadata_program: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub rent: Sysvar<'info, Rent>,
+	}
+
+/// Mint a receipt NFT for a donation
+///
+/// The receipt mint is a PDA of the donation record, so every receipt can be
+/// traced back to the `Donation` account holding the donor, dog and amount. The
+/// on-chain name shows the amount and the dog, e.g. "Woofi 1.5 SOL: Rex"; the
+/// off-chain JSON at `uri` carries the rest as attributes.
+///
+/// Accounts:
+/// 0. `[writable, signer]` donor: [AccountInfo] The donor who made the donation
+/// 1. `[]` platform: [Platform] The platform configuration account, mint and update authority
+/// 2. `[]` dog: [Dog] The dog that received the donation
+/// 3. `[writable]` donation: [Donation] The donation record the receipt is minted for
+/// 4. `[writable]` mint: [AccountInfo] The receipt mint to be created
+/// 5. `[writable]` receipt_token_account: [AccountInfo] The donor's token account holding the receipt
+/// 6. `[writable]` metadata: [AccountInfo] The Metaplex metadata account of the receipt mint
+/// 7. `[]` token_program: [AccountInfo] SPL Token program
+/// 8. `[]` token_metadata_program: [AccountInfo] Metaplex Token Metadata program
+/// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 10. `[]` rent: [AccountInfo] Rent sysvar
+///
+/// Data:
+/// - uri: [String] URI of the off-chain receipt metadata JSON
+pub fn handler(
+	ctx: Context<MintReceipt>,
+	uri: String,
+) -> Result<()> {
+    // Refuse while donations are paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DONATIONS)?;
+
+    let platform = &ctx.accounts.platform;
+    let donation = &ctx.accounts.donation;
+
+    // Validate the receipt is allowed for this donation
+    if !platform.receipts_enabled {
+        return err!(error::WoofiError::ReceiptsDisabled);
+    }
+
+    if donation.amount < platform.receipt_min_amount {
+        return err!(error::WoofiError::DonationBelowReceiptMinimum);
+    }
+
+    if donation.receipt_mint != Pubkey::default() {
+        return err!(error::WoofiError::ReceiptAlreadyMinted);
+    }
+
+    if uri.len() > MAX_METADATA_URI_LEN {
+        return err!(error::WoofiError::InvalidReceiptUri);
+    }
+
+    let platform_seeds: &[&[u8]] = &[b"platform", &[ctx.bumps.platform]];
+    let signer_seeds = &[platform_seeds];
+
+    // Initialize the receipt mint and the donor's token account
+    token::initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        0,
+        &ctx.accounts.platform.key(),
+        None,
+    )?;
+
+    token::initialize_account3(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        InitializeAccount3 {
+            account: ctx.accounts.receipt_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.donor.to_account_info(),
+        },
+    ))?;
+
+    // Mint the single receipt token to the donor
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.receipt_token_account.to_account_info(),
+                authority: ctx.accounts.platform.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    // Attach Metaplex metadata naming the amount and the dog, truncating the dog's name
+    let mut name = format!("Woofi {} SOL: {}", format_sol(donation.amount), ctx.accounts.dog.name);
+    if name.len() > MAX_METADATA_NAME_LEN {
+        let mut end = MAX_METADATA_NAME_LEN;
+        while !name.is_char_boundary(end) {
+            end -= 1;
+        }
+        name.truncate(end);
+    }
+
+    let token_metadata_program = ctx.accounts.token_metadata_program.to_account_info();
+    let metadata = ctx.accounts.metadata.to_account_info();
+    let mint = ctx.accounts.mint.to_account_info();
+    let platform_info = ctx.accounts.platform.to_account_info();
+    let donor = ctx.accounts.donor.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let rent = ctx.accounts.rent.to_account_info();
+
+    CreateMetadataAccountV3CpiBuilder::new(&token_metadata_program)
+        .metadata(&metadata)
+        .mint(&mint)
+        .mint_authority(&platform_info)
+        .payer(&donor)
+        .update_authority(&platform_info, true)
+        .system_program(&system_program)
+        .rent(Some(&rent))
+        .data(DataV2 {
+            name,
+            symbol: RECEIPT_SYMBOL.to_string(),
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        })
+        .is_mutable(false)
+        .invoke_signed(signer_seeds)?;
+
+    // Revoke the mint authority so the supply stays fixed at one
+    token::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.platform.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        spl_token::instruction::AuthorityType::MintTokens,
+        None,
+    )?;
+
+    let donation = &mut ctx.accounts.donation;
+    donation.receipt_mint = ctx.accounts.mint.key();
+
+    msg!("Minted receipt {} for donation of {} lamports to dog: {}", donation.receipt_mint, donation.amount, ctx.accounts.dog.name);
+
+	Ok(())
+}
+
+/// Format lamports as SOL without trailing zeros, e.g. `1.5`
+fn format_sol(lamports: u64) -> String {
+    let sol = format!("{}.{:09}", lamports / LAMPORTS_PER_SOL, lamports % LAMPORTS_PER_SOL);
+    sol.trim_end_matches('0').trim_end_matches('.').to_string()
+}
@@ -4,9 +4,91 @@ pub mod add_dog;
 pub mod update_dog;
 pub mod donate;
 pub mod withdraw_funds;
+pub mod set_receipt_config;
+pub mod mint_receipt;
+pub mod add_curator;
+pub mod remove_curator;
+pub mod set_moderation_config;
+pub mod hide_donation_message;
+pub mod edit_donation_message;
+pub mod reply_to_donation;
+pub mod register_shelter;
+pub mod verify_shelter;
+pub mod revoke_shelter;
+pub mod set_shelter_policy;
+pub mod set_pause;
+pub mod set_guardian;
+pub mod update_treasury;
+pub mod set_treasury_timelock;
+pub mod add_payee;
+pub mod remove_payee;
+pub mod set_payee_policy;
+pub mod set_withdraw_limit;
+pub mod reconcile;
+pub mod migrate_platform;
+pub mod migrate_dog;
+pub mod migrate_donation;
+pub mod assign_legacy_dog;
+pub mod add_dog_media;
+pub mod remove_dog_media;
+pub mod reorder_dog_media;
+pub mod add_need;
+pub mod close_need;
+pub mod add_medical_record;
+pub mod post_dog_update;
+pub mod submit_adoption_application;
+pub mod review_adoption_application;
+pub mod register_foster;
+pub mod verify_foster;
+pub mod assign_foster;
+pub mod end_foster;
+pub mod propose_dog_transfer;
+pub mod accept_dog_transfer;
+pub mod cancel_dog_transfer;
 
 pub use initialize_platform::*;
 pub use add_dog::*;
 pub use update_dog::*;
 pub use donate::*;
 pub use withdraw_funds::*;
+pub use set_receipt_config::*;
+pub use mint_receipt::*;
+pub use add_curator::*;
+pub use remove_curator::*;
+pub use set_moderation_config::*;
+pub use hide_donation_message::*;
+pub use edit_donation_message::*;
+pub use reply_to_donation::*;
+pub use register_shelter::*;
+pub use verify_shelter::*;
+pub use revoke_shelter::*;
+pub use set_shelter_policy::*;
+pub use set_pause::*;
+pub use set_guardian::*;
+pub use update_treasury::*;
+pub use set_treasury_timelock::*;
+pub use add_payee::*;
+pub use remove_payee::*;
+pub use set_payee_policy::*;
+pub use set_withdraw_limit::*;
+pub use reconcile::*;
+pub use migrate_platform::*;
+pub use migrate_dog::*;
+pub use migrate_donation::*;
+pub use assign_legacy_dog::*;
+pub use add_dog_media::*;
+pub use remove_dog_media::*;
+pub use reorder_dog_media::*;
+pub use add_need::*;
+pub use close_need::*;
+pub use add_medical_record::*;
+pub use post_dog_update::*;
+pub use submit_adoption_application::*;
+pub use review_adoption_application::*;
+pub use register_foster::*;
+pub use verify_foster::*;
+pub use assign_foster::*;
+pub use end_foster::*;
+pub use propose_dog_transfer::*;
+pub use accept_dog_transfer::*;
+pub use cancel_dog_transfer::*;
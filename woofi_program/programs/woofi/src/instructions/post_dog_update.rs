@@ -0,0 +1,118 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		title: String,
+		body: String,
+		body_hash: [u8; 32],
+		media_uri: String,
+	)]
+	pub struct PostDogUpdate<'info> {
+		#[account(
+			mut,
+            constraint = author.key() == dog.admin || Curator::is_authorized(
+                &author.key(),
+                &platform,
+                curator.as_deref(),
+            ) @ error::WoofiError::Unauthorized
+        )]
+		pub author: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			seeds = [
+				b"curator",
+				author.key().as_ref(),
+			],
+			bump,
+		)]
+		pub curator: Option<Account<'info, Curator>>,
+
+		#[account(
+			mut,
+		)]
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			init,
+			space=676,
+			payer=author,
+			seeds = [
+				b"dog_update",
+				dog.key().as_ref(),
+				dog.update_count.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub dog_update: Account<'info, DogUpdate>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Post a progress update to a dog's news feed
+///
+/// Accounts:
+/// 0. `[writable, signer]` author: [AccountInfo] The dog's admin, the platform admin or a curator
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` curator: [Curator] (optional) The author's curator record
+/// 3. `[writable]` dog: [Dog] The dog the update is about
+/// 4. `[writable]` dog_update: [DogUpdate] The update account to be created
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - title: [String] Headline of the update
+/// - body: [String] Inline text of the update, may be empty with a body hash
+/// - body_hash: [[u8; 32]] SHA-256 of an off-chain body, zero for inline bodies
+/// - media_uri: [String] Optional photo or video of the update
+pub fn handler(
+	ctx: Context<PostDogUpdate>,
+	title: String,
+	body: String,
+	body_hash: [u8; 32],
+	media_uri: String,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    if title.trim().is_empty()
+        || title.len() > MAX_UPDATE_TITLE_LEN
+        || body.len() > MAX_UPDATE_BODY_LEN
+        || (body.trim().is_empty() && body_hash == [0; 32])
+        || (!media_uri.is_empty() && (!is_supported_uri(&media_uri) || media_uri.len() > MAX_MEDIA_URI_LEN))
+    {
+        return err!(error::WoofiError::InvalidDogUpdate);
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let dog = &mut ctx.accounts.dog;
+    let dog_update = &mut ctx.accounts.dog_update;
+    dog_update.dog = dog.key();
+    dog_update.index = dog.update_count;
+    dog_update.title = title;
+    dog_update.body = body;
+    dog_update.body_hash = body_hash;
+    dog_update.media_uri = media_uri;
+    dog_update.author = ctx.accounts.author.key();
+    dog_update.posted_at = now;
+
+    dog.update_count = dog.update_count.checked_add(1).ok_or(error::WoofiError::MathOverflow)?;
+
+    emit!(DogUpdatePosted {
+        dog: dog.key(),
+        update: dog_update.key(),
+        index: dog_update.index,
+        posted_at: now,
+    });
+
+    msg!("Posted update {} for dog {}", dog_update.index, dog.name);
+
+	Ok(())
+}
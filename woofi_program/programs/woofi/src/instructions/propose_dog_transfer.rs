@@ -0,0 +1,76 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct ProposeDogTransfer<'info> {
+		#[account(
+			mut,
+            constraint = admin.key() == from_shelter.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		pub from_shelter: Account<'info, Shelter>,
+
+		#[account(
+            constraint = to_shelter.key() != from_shelter.key() @ error::WoofiError::InvalidDogTransfer
+		)]
+		pub to_shelter: Account<'info, Shelter>,
+
+		#[account(
+            constraint = dog.owner_shelter == from_shelter.key() @ error::WoofiError::ShelterMismatch
+		)]
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			init,
+			space=144,
+			payer=admin,
+			seeds = [
+				b"dog_transfer",
+				dog.key().as_ref(),
+			],
+			bump,
+		)]
+		pub transfer: Account<'info, DogTransfer>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Propose moving a dog to another shelter; the move happens once the
+/// destination shelter's admin accepts it
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The source shelter's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` from_shelter: [Shelter] The shelter currently owning the dog
+/// 3. `[]` to_shelter: [Shelter] The shelter the dog moves to
+/// 4. `[]` dog: [Dog] The dog to transfer
+/// 5. `[writable]` transfer: [DogTransfer] The pending transfer to be created
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+pub fn handler(
+	ctx: Context<ProposeDogTransfer>,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    ctx.accounts.dog.check_available()?;
+
+    let transfer = &mut ctx.accounts.transfer;
+    transfer.dog = ctx.accounts.dog.key();
+    transfer.from_shelter = ctx.accounts.from_shelter.key();
+    transfer.to_shelter = ctx.accounts.to_shelter.key();
+    transfer.proposed_by = ctx.accounts.admin.key();
+    transfer.proposed_at = Clock::get()?.unix_timestamp;
+
+    msg!("Proposed moving dog {} to shelter {}", ctx.accounts.dog.name, ctx.accounts.to_shelter.name);
+
+	Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct Reconcile<'info> {
+		pub shelter: Account<'info, Shelter>,
+
+		#[account(
+            address = shelter.treasury @ error::WoofiError::Unauthorized
+		)]
+		/// CHECK: Treasury address is verified against shelter.treasury
+		pub treasury: UncheckedAccount<'info>,
+	}
+
+/// Result of comparing a shelter treasury to its books
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Reconciliation {
+	/// Lamports the treasury should hold: its opening balance plus donations minus
+	/// withdrawals since it became the shelter's treasury
+	pub booked: u64,
+	/// Lamports the treasury actually holds
+	pub actual: u64,
+	/// `actual - booked`, positive when the treasury received funds outside `donate`
+	pub discrepancy: i64,
+}
+
+/// Compare a shelter treasury's lamports to its recorded donations and withdrawals
+///
+/// The treasury is expected to hold what it held when it became the shelter's
+/// treasury plus the donations minus the withdrawals booked since, so rent and
+/// funds left in earlier treasuries are not reported. Read-only and permissionless, so it can be simulated to read the returned
+/// `Reconciliation`. Emits `TreasuryDiscrepancy` when the balances differ.
+///
+/// Accounts:
+/// 0. `[]` shelter: [Shelter] The shelter to reconcile
+/// 1. `[]` treasury: [AccountInfo] The shelter's treasury wallet
+pub fn handler(
+	ctx: Context<Reconcile>,
+) -> Result<Reconciliation> {
+    let shelter = &ctx.accounts.shelter;
+    let booked = shelter.expected_treasury_balance()?;
+    let actual = ctx.accounts.treasury.lamports();
+    let discrepancy = i64::try_from(actual as i128 - booked as i128).map_err(|_| error::WoofiError::MathOverflow)?;
+
+    if discrepancy != 0 {
+        emit!(TreasuryDiscrepancy {
+            shelter: shelter.key(),
+            treasury: shelter.treasury,
+            booked,
+            actual,
+            discrepancy,
+        });
+    }
+
+    msg!("Shelter {} booked {} lamports, treasury holds {}", shelter.name, booked, actual);
+
+	Ok(Reconciliation {
+		booked,
+		actual,
+		discrepancy,
+	})
+}
@@ -0,0 +1,72 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		region: String,
+		capacity: u8,
+	)]
+	pub struct RegisterFoster<'info> {
+		#[account(
+			mut,
+		)]
+		pub wallet: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			init,
+			space=119,
+			payer=wallet,
+			seeds = [
+				b"foster",
+				wallet.key().as_ref(),
+			],
+			bump,
+		)]
+		pub foster: Account<'info, Foster>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Register as a foster volunteer, pending verification by the platform
+///
+/// Accounts:
+/// 0. `[writable, signer]` wallet: [AccountInfo] The volunteer's wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` foster: [Foster] The foster record to be created
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - region: [String] Where the foster lives
+/// - capacity: [u8] Number of dogs the foster can care for at once
+pub fn handler(
+	ctx: Context<RegisterFoster>,
+	region: String,
+	capacity: u8,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    if region.trim().is_empty() || region.len() > MAX_FOSTER_REGION_LEN || capacity == 0 {
+        return err!(error::WoofiError::InvalidFoster);
+    }
+
+    let foster = &mut ctx.accounts.foster;
+    foster.wallet = ctx.accounts.wallet.key();
+    foster.region = region;
+    foster.capacity = capacity;
+    foster.dog_count = 0;
+    foster.verified = false;
+    foster.verified_at = 0;
+
+    msg!("Registered foster {} in {}", foster.wallet, foster.region);
+
+	Ok(())
+}
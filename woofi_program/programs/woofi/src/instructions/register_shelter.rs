@@ -0,0 +1,105 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		treasury: Pubkey,
+		shelter_admin: Pubkey,
+	)]
+	pub struct RegisterShelter<'info> {
+		#[account(
+			mut,
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized,
+            // Checked before the shelter PDA is derived from the name
+            constraint = name.len() <= MAX_SHELTER_NAME_LEN @ error::WoofiError::InvalidShelterName
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			init,
+			space=8 + Shelter::INIT_SPACE,
+			payer=admin,
+			seeds = [
+				b"shelter",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub shelter: Account<'info, Shelter>,
+
+		#[account(
+            address = treasury @ error::WoofiError::Unauthorized
+		)]
+		/// CHECK: Treasury address is verified against the treasury argument, read for its opening balance
+		pub shelter_treasury: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Register a shelter organization on the platform. Onboarding goes through the
+/// platform admin, who names the wallet that will administer the shelter.
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The platform admin wallet
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter account to be created
+/// 3. `[]` shelter_treasury: [AccountInfo] The treasury wallet, whose balance reconciliation starts from
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] Name of the shelter
+/// - treasury: [Pubkey] Treasury wallet receiving donations to the shelter's dogs
+/// - shelter_admin: [Pubkey] Wallet administering the shelter and its dogs
+pub fn handler(
+	ctx: Context<RegisterShelter>,
+	name: String,
+	treasury: Pubkey,
+	shelter_admin: Pubkey,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    // Validate inputs
+    if name.trim().is_empty() || name.len() > MAX_SHELTER_NAME_LEN {
+        return err!(error::WoofiError::InvalidShelterName);
+    }
+
+    initialize_platform::validate_treasury(&treasury, ctx.program_id)?;
+
+    // Initialize the shelter account
+    let shelter = &mut ctx.accounts.shelter;
+    shelter.name = name;
+    shelter.admin = shelter_admin;
+    shelter.total_donations = 0;
+    shelter.dog_count = 0;
+    shelter.donation_count = 0;
+    shelter.verified = false;
+    shelter.verification_hash = [0; 32];
+    shelter.verified_at = 0;
+    shelter.withdrawal_count = 0;
+    shelter.period_start = 0;
+    shelter.period_withdrawn = 0;
+    shelter.total_withdrawn = 0;
+    shelter.transferred_in = 0;
+    shelter.transferred_out = 0;
+    shelter.open_treasury(treasury, ctx.accounts.shelter_treasury.lamports());
+
+    // Update platform stats
+    let platform = &mut ctx.accounts.platform;
+    platform.shelter_count = platform.shelter_count.checked_add(1).ok_or(error::WoofiError::MathOverflow)?;
+
+    msg!("Registered shelter: {}", shelter.name);
+    msg!("Treasury: {}", shelter.treasury);
+
+	Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct RemoveCurator<'info> {
+		#[account(
+			mut,
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+			close=admin,
+			seeds = [
+				b"curator",
+				curator.wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub curator: Account<'info, Curator>,
+	}
+
+/// Revoke a wallet's curator rights
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` curator: [Curator] The curator record to be closed
+pub fn handler(
+	ctx: Context<RemoveCurator>,
+) -> Result<()> {
+    msg!("Removed curator: {}", ctx.accounts.curator.wallet);
+
+	Ok(())
+}
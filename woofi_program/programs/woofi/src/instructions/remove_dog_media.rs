@@ -0,0 +1,57 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		index: u8,
+	)]
+	pub struct RemoveDogMedia<'info> {
+		#[account(
+            constraint = admin.key() == dog.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"dog_media",
+				dog.key().as_ref(),
+			],
+			bump,
+		)]
+		pub dog_media: Account<'info, DogMedia>,
+	}
+
+/// Remove an entry from a dog's gallery, keeping the order of the others
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` dog: [Dog] The dog the gallery belongs to
+/// 3. `[writable]` dog_media: [DogMedia] The dog's gallery
+///
+/// Data:
+/// - index: [u8] Position of the entry to remove
+pub fn handler(
+	ctx: Context<RemoveDogMedia>,
+	index: u8,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    let removed = ctx.accounts.dog_media.remove(index)?;
+
+    msg!("Removed media {} for dog {}", removed.uri, ctx.accounts.dog.name);
+
+	Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct RemovePayee<'info> {
+		#[account(
+			mut,
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+			close=admin,
+			seeds = [
+				b"payee",
+				payee.wallet.as_ref(),
+			],
+			bump,
+		)]
+		pub payee: Account<'info, Payee>,
+	}
+
+/// Remove a wallet from the payee registry
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` payee: [Payee] The payee record to be closed
+pub fn handler(
+	ctx: Context<RemovePayee>,
+) -> Result<()> {
+    msg!("Removed payee {}: {}", ctx.accounts.payee.label, ctx.accounts.payee.wallet);
+
+	Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		order: Vec<u8>,
+	)]
+	pub struct ReorderDogMedia<'info> {
+		#[account(
+            constraint = admin.key() == dog.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"dog_media",
+				dog.key().as_ref(),
+			],
+			bump,
+		)]
+		pub dog_media: Account<'info, DogMedia>,
+	}
+
+/// Change the display order of a dog's gallery
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` dog: [Dog] The dog the gallery belongs to
+/// 3. `[writable]` dog_media: [DogMedia] The dog's gallery
+///
+/// Data:
+/// - order: [Vec<u8>] Current positions of the entries, listed in their new order
+pub fn handler(
+	ctx: Context<ReorderDogMedia>,
+	order: Vec<u8>,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    ctx.accounts.dog_media.reorder(&order)?;
+
+    msg!("Reordered media for dog {}", ctx.accounts.dog.name);
+
+	Ok(())
+}
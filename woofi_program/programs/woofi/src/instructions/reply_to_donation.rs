@@ -0,0 +1,68 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		thank_you: String,
+	)]
+	pub struct ReplyToDonation<'info> {
+		#[account(
+            constraint = Curator::is_authorized(
+                &curator_signer.key(),
+                &platform,
+                curator.as_deref(),
+            ) @ error::WoofiError::Unauthorized
+        )]
+		pub curator_signer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			seeds = [
+				b"curator",
+				curator_signer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub curator: Option<Account<'info, Curator>>,
+
+		#[account(
+			mut,
+		)]
+		pub donation: Account<'info, Donation>,
+	}
+
+/// Attach a public thank-you reply to a donation
+///
+/// Accounts:
+/// 0. `[signer]` curator_signer: [AccountInfo] The platform admin or a curator
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` curator: [Curator] (optional) The signer's curator record
+/// 3. `[writable]` donation: [Donation] The donation being replied to
+///
+/// Data:
+/// - thank_you: [String] The reply, empty to remove it
+pub fn handler(
+	ctx: Context<ReplyToDonation>,
+	thank_you: String,
+) -> Result<()> {
+    // Refuse while donations are paused, like the donor's own message edits
+    ctx.accounts.platform.check_not_paused(PAUSE_DONATIONS)?;
+
+    if thank_you.len() > MAX_MESSAGE_LEN {
+        return err!(error::WoofiError::MessageTooLong);
+    }
+
+    let donation = &mut ctx.accounts.donation;
+    donation.thank_you = thank_you;
+
+    msg!("Replied to donation: {}", donation.key());
+
+	Ok(())
+}
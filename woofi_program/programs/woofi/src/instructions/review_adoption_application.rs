@@ -0,0 +1,89 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		status: ApplicationStatus,
+	)]
+	pub struct ReviewAdoptionApplication<'info> {
+		#[account(
+            constraint = Curator::is_authorized(
+                &reviewer.key(),
+                &platform,
+                curator.as_deref(),
+            ) @ error::WoofiError::Unauthorized
+        )]
+		pub reviewer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			seeds = [
+				b"curator",
+				reviewer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub curator: Option<Account<'info, Curator>>,
+
+		#[account(
+			mut,
+		)]
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"adoption",
+				dog.key().as_ref(),
+				application.applicant.as_ref(),
+			],
+			bump,
+		)]
+		pub application: Account<'info, AdoptionApplication>,
+	}
+
+/// Move an adoption application forward: take it under review, then approve or
+/// reject it. Approving marks the dog as adopted, which stops donations to it.
+///
+/// Accounts:
+/// 0. `[signer]` reviewer: [AccountInfo] The platform admin or a curator
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` curator: [Curator] (optional) The reviewer's curator record
+/// 3. `[writable]` dog: [Dog] The dog applied for
+/// 4. `[writable]` application: [AdoptionApplication] The application to review
+///
+/// Data:
+/// - status: [ApplicationStatus] UnderReview, Approved or Rejected
+pub fn handler(
+	ctx: Context<ReviewAdoptionApplication>,
+	status: ApplicationStatus,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    // Applications for adopted dogs can no longer move, except to be rejected
+    let dog = &mut ctx.accounts.dog;
+    if status != ApplicationStatus::Rejected {
+        dog.check_available()?;
+    }
+
+    let application = &mut ctx.accounts.application;
+    application.transition(status, ctx.accounts.reviewer.key(), Clock::get()?.unix_timestamp)?;
+
+    if status == ApplicationStatus::Approved {
+        dog.lifecycle = DogLifecycle::Adopted;
+        dog.active = false;
+        msg!("Dog {} adopted by {}", dog.name, application.applicant);
+    } else {
+        msg!("Adoption application of {} for dog {} is now {:?}", application.applicant, dog.name, status);
+    }
+
+	Ok(())
+}
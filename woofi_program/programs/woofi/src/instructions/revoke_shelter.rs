@@ -0,0 +1,41 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct RevokeShelter<'info> {
+		#[account(
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+		)]
+		pub shelter: Account<'info, Shelter>,
+	}
+
+/// Revoke a shelter's verified status
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter to revoke
+pub fn handler(
+	ctx: Context<RevokeShelter>,
+) -> Result<()> {
+    let shelter = &mut ctx.accounts.shelter;
+    shelter.verified = false;
+    shelter.verification_hash = [0; 32];
+
+    msg!("Revoked verification of shelter: {}", shelter.name);
+
+	Ok(())
+}
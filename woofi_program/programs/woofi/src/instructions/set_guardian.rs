@@ -0,0 +1,42 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		guardian: Pubkey,
+	)]
+	pub struct SetGuardian<'info> {
+		#[account(
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+	}
+
+/// Set the guardian key allowed to pause the program
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - guardian: [Pubkey] The new guardian, the default pubkey to remove it
+pub fn handler(
+	ctx: Context<SetGuardian>,
+	guardian: Pubkey,
+) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    platform.guardian = guardian;
+
+    msg!("Guardian set to {}", guardian);
+
+	Ok(())
+}
@@ -0,0 +1,70 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		max_urls: u8,
+		banned_substrings: Vec<String>,
+	)]
+	pub struct SetModerationConfig<'info> {
+		#[account(
+			mut,
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			init_if_needed,
+			space=589,
+			payer=admin,
+			seeds = [
+				b"moderation",
+			],
+			bump,
+		)]
+		pub moderation: Account<'info, ModerationConfig>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Set the moderation rules enforced on donation messages
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` moderation: [ModerationConfig] The moderation config, created on first use
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - max_urls: [u8] Maximum number of links allowed in a donation message
+/// - banned_substrings: [Vec<String>] Case-insensitive substrings rejected in donation messages
+pub fn handler(
+	ctx: Context<SetModerationConfig>,
+	max_urls: u8,
+	banned_substrings: Vec<String>,
+) -> Result<()> {
+    // Validate the list fits in the account
+    if banned_substrings.len() > MAX_BANNED_SUBSTRINGS
+        || banned_substrings
+            .iter()
+            .any(|banned| banned.trim().is_empty() || banned.len() > MAX_BANNED_SUBSTRING_LEN)
+    {
+        return err!(error::WoofiError::InvalidModerationConfig);
+    }
+
+    let moderation = &mut ctx.accounts.moderation;
+    moderation.max_urls = max_urls;
+    moderation.banned_substrings = banned_substrings;
+
+    msg!("Moderation config updated: {} banned substrings, max {} links", moderation.banned_substrings.len(), max_urls);
+
+	Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		paused: u8,
+	)]
+	pub struct SetPause<'info> {
+		#[account(
+            constraint = authority.key() == platform.admin
+                || (platform.guardian != Pubkey::default() && authority.key() == platform.guardian)
+                @ error::WoofiError::Unauthorized
+        )]
+		pub authority: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+	}
+
+/// Pause or resume groups of instructions
+///
+/// The admin may set any combination of flags, the guardian may only add flags so a
+/// compromised guardian key cannot resume the program. Admin configuration and
+/// message moderation stay available while paused.
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The platform admin or guardian
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - paused: [u8] Bitmask of `PAUSE_DONATIONS`, `PAUSE_WITHDRAWALS` and `PAUSE_DOG_MANAGEMENT`
+pub fn handler(
+	ctx: Context<SetPause>,
+	paused: u8,
+) -> Result<()> {
+    if paused & !PAUSE_ALL != 0 {
+        return err!(error::WoofiError::InvalidPauseFlags);
+    }
+
+    let platform = &mut ctx.accounts.platform;
+    if ctx.accounts.authority.key() != platform.admin && paused & platform.paused != platform.paused {
+        return err!(error::WoofiError::Unauthorized);
+    }
+
+    platform.paused = paused;
+
+    msg!("Pause flags set to {:#05b}", paused);
+
+	Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		require_registered_payees: bool,
+	)]
+	pub struct SetPayeePolicy<'info> {
+		#[account(
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+	}
+
+/// Configure whether withdrawals are restricted to registered payees
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - require_registered_payees: [bool] Only allow withdrawals to registered payees
+pub fn handler(
+	ctx: Context<SetPayeePolicy>,
+	require_registered_payees: bool,
+) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    platform.require_registered_payees = require_registered_payees;
+
+    msg!("Require registered payees: {}", require_registered_payees);
+
+	Ok(())
+}
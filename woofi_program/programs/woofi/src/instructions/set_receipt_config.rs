@@ -0,0 +1,46 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		enabled: bool,
+		min_amount: u64,
+	)]
+	pub struct SetReceiptConfig<'info> {
+		#[account(
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+	}
+
+/// Configure receipt NFT minting for donations
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - enabled: [bool] Whether donors may mint receipt NFTs
+/// - min_amount: [u64] Minimum donation in lamports that qualifies for a receipt
+pub fn handler(
+	ctx: Context<SetReceiptConfig>,
+	enabled: bool,
+	min_amount: u64,
+) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    platform.receipts_enabled = enabled;
+    platform.receipt_min_amount = min_amount;
+
+    msg!("Receipt NFTs enabled: {}, minimum: {} lamports", enabled, min_amount);
+
+	Ok(())
+}
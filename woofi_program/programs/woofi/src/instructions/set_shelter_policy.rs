@@ -0,0 +1,45 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		require_verified_shelters: bool,
+	)]
+	pub struct SetShelterPolicy<'info> {
+		#[account(
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+	}
+
+/// Configure whether donations to unverified shelters are accepted
+///
+/// When the policy is off, donations to unverified shelters still succeed and are
+/// flagged through `Donation::shelter_verified`.
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - require_verified_shelters: [bool] Reject donations to unverified shelters
+pub fn handler(
+	ctx: Context<SetShelterPolicy>,
+	require_verified_shelters: bool,
+) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    platform.require_verified_shelters = require_verified_shelters;
+
+    msg!("Require verified shelters: {}", require_verified_shelters);
+
+	Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		timelock_secs: i64,
+	)]
+	pub struct SetTreasuryTimelock<'info> {
+		#[account(
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+	}
+
+/// Set the delay applied to shelter treasury changes
+///
+/// The timelock can only grow, so a compromised admin key cannot remove it to
+/// redirect funds immediately.
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - timelock_secs: [i64] Seconds between proposing and applying a treasury change
+pub fn handler(
+	ctx: Context<SetTreasuryTimelock>,
+	timelock_secs: i64,
+) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    if timelock_secs < platform.treasury_timelock_secs || timelock_secs > MAX_TREASURY_TIMELOCK_SECONDS {
+        return err!(error::WoofiError::InvalidTimelock);
+    }
+
+    platform.treasury_timelock_secs = timelock_secs;
+
+    msg!("Treasury timelock set to {} seconds", timelock_secs);
+
+	Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		max_withdraw_per_period: u64,
+		withdraw_period_secs: i64,
+	)]
+	pub struct SetWithdrawLimit<'info> {
+		#[account(
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+	}
+
+/// Cap how much each shelter can withdraw per period
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - max_withdraw_per_period: [u64] Lamports each shelter may withdraw per period, 0 for no limit
+/// - withdraw_period_secs: [i64] Length of the withdrawal period in seconds
+pub fn handler(
+	ctx: Context<SetWithdrawLimit>,
+	max_withdraw_per_period: u64,
+	withdraw_period_secs: i64,
+) -> Result<()> {
+    if max_withdraw_per_period > 0 && withdraw_period_secs <= 0 {
+        return err!(error::WoofiError::InvalidWithdrawPeriod);
+    }
+
+    let platform = &mut ctx.accounts.platform;
+    platform.max_withdraw_per_period = max_withdraw_per_period;
+    platform.withdraw_period_secs = withdraw_period_secs;
+
+    msg!("Withdrawals limited to {} lamports per {} seconds", max_withdraw_per_period, withdraw_period_secs);
+
+	Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		contact_hash: [u8; 32],
+	)]
+	pub struct SubmitAdoptionApplication<'info> {
+		#[account(
+			mut,
+		)]
+		pub applicant: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		pub dog: Account<'info, Dog>,
+
+		#[account(
+			init,
+			space=153,
+			payer=applicant,
+			seeds = [
+				b"adoption",
+				dog.key().as_ref(),
+				applicant.key().as_ref(),
+			],
+			bump,
+		)]
+		pub application: Account<'info, AdoptionApplication>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Apply to adopt a dog
+///
+/// Accounts:
+/// 0. `[writable, signer]` applicant: [AccountInfo] The wallet applying to adopt
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` dog: [Dog] The dog to adopt
+/// 3. `[writable]` application: [AdoptionApplication] The application account to be created
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - contact_hash: [[u8; 32]] Hash of the applicant's contact details, shared off-chain
+pub fn handler(
+	ctx: Context<SubmitAdoptionApplication>,
+	contact_hash: [u8; 32],
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    ctx.accounts.dog.check_available()?;
+
+    if contact_hash == [0; 32] {
+        return err!(error::WoofiError::InvalidContactHash);
+    }
+
+    let application = &mut ctx.accounts.application;
+    application.dog = ctx.accounts.dog.key();
+    application.applicant = ctx.accounts.applicant.key();
+    application.contact_hash = contact_hash;
+    application.status = ApplicationStatus::Submitted;
+    application.submitted_at = Clock::get()?.unix_timestamp;
+    application.reviewed_by = Pubkey::default();
+    application.reviewed_at = 0;
+
+    msg!("Adoption application for dog {} submitted by {}", ctx.accounts.dog.name, application.applicant);
+
+	Ok(())
+}
@@ -6,12 +6,12 @@ use std::str::FromStr;
 	#[instruction(
 		image_url: String,
 		story: String,
-		needs_food: bool,
-		needs_toys: bool,
-		needs_medical: bool,
-		needs_shelter: bool,
-		needs_other: String,
 		active: bool,
+		profile: DogProfile,
+		intake_date: i64,
+		birth_date: i64,
+		metadata_uri: String,
+		metadata_hash: [u8; 32],
 	)]
 	pub struct UpdateDog<'info> {
 		#[account(
@@ -19,6 +19,14 @@ use std::str::FromStr;
         )]
 		pub admin: Signer<'info>,
 
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
 		#[account(
 			mut,
 		)]
@@ -29,47 +37,46 @@ use std::str::FromStr;
 ///
 /// Accounts:
 /// 0. `[signer]` admin: [AccountInfo] 
-/// 1. `[writable]` dog: [Dog] The dog account to be updated
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` dog: [Dog] The dog account to be updated
 ///
 /// Data:
 /// - image_url: [String] Updated URL to the dog's image
-/// - story: [String] Updated rescue story of the dog
-/// - needs_food: [bool] Whether the dog needs food
-/// - needs_toys: [bool] Whether the dog needs toys
-/// - needs_medical: [bool] Whether the dog needs medical support
-/// - needs_shelter: [bool] Whether the dog needs shelter
-/// - needs_other: [String] Other specific needs
+/// - story: [String] Updated rescue story of the dog, may be empty with a metadata document
 /// - active: [bool] Whether the dog is currently active on the platform
+/// - profile: [DogProfile] Breed, sex, size, weight, medical status and location
+/// - intake_date: [i64] Corrected date the shelter took the dog in
+/// - birth_date: [i64] Corrected estimated date of birth, 0 when unknown
+/// - metadata_uri: [String] Optional JSON document with the full story, photos and vet notes
+/// - metadata_hash: [[u8; 32]] SHA-256 of the metadata document, zero without one
 pub fn handler(
 	ctx: Context<UpdateDog>,
 	image_url: String,
 	story: String,
-	needs_food: bool,
-	needs_toys: bool,
-	needs_medical: bool,
-	needs_shelter: bool,
-	needs_other: String,
 	active: bool,
+	profile: DogProfile,
+	intake_date: i64,
+	birth_date: i64,
+	metadata_uri: String,
+	metadata_hash: [u8; 32],
 ) -> Result<()> {
-    // Validate inputs
-    if story.trim().is_empty() {
-        return err!(error::WoofiError::InvalidDogStory);
-    }
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
 
+    // Validate inputs
     if image_url.trim().is_empty() {
         return err!(error::WoofiError::InvalidImageUrl);
     }
 
+    profile.validate()?;
+
     // Update the dog account
     let dog = &mut ctx.accounts.dog;
     dog.image_url = image_url;
-    dog.story = story;
-    dog.needs_food = needs_food;
-    dog.needs_toys = needs_toys;
-    dog.needs_medical = needs_medical;
-    dog.needs_shelter = needs_shelter;
-    dog.needs_other = needs_other;
     dog.active = active;
+    dog.profile = profile;
+    dog.set_story(story, metadata_uri, metadata_hash)?;
+    dog.set_dates(intake_date, birth_date, Clock::get()?.unix_timestamp)?;
 
     msg!("Updated dog: {}", dog.name);
 	
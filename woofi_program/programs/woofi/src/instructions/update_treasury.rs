@@ -0,0 +1,119 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		treasury: Pubkey,
+	)]
+	pub struct UpdateTreasury<'info> {
+		#[account(
+			mut,
+            constraint = admin.key() == shelter.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+		)]
+		pub shelter: Account<'info, Shelter>,
+
+		#[account(
+			init_if_needed,
+			space=690,
+			payer=admin,
+			seeds = [
+				b"treasury_history",
+				shelter.key().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury_history: Account<'info, TreasuryHistory>,
+
+		#[account(
+            address = treasury @ error::WoofiError::Unauthorized
+		)]
+		/// CHECK: Treasury address is verified against the treasury argument, read for its opening balance
+		pub new_treasury: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Change the treasury receiving donations to a shelter's dogs
+///
+/// Without a platform timelock the change applies immediately. With a timelock the
+/// first call proposes the treasury, and calling again with the same treasury once
+/// the timelock has expired applies it. Proposing another treasury restarts the delay.
+/// Funds already held by the previous treasury stay there, and reconciliation
+/// starts over from the new treasury's balance.
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The shelter admin
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter whose treasury changes
+/// 3. `[writable]` treasury_history: [TreasuryHistory] Pending and previous treasuries of the shelter, created on first use
+/// 4. `[]` new_treasury: [AccountInfo] The new treasury wallet, whose balance reconciliation starts from
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - treasury: [Pubkey] The new treasury wallet
+pub fn handler(
+	ctx: Context<UpdateTreasury>,
+	treasury: Pubkey,
+) -> Result<()> {
+    // Refuse while withdrawals are paused, so funds cannot be redirected during an incident
+    ctx.accounts.platform.check_not_paused(PAUSE_WITHDRAWALS)?;
+
+    initialize_platform::validate_treasury(&treasury, ctx.program_id)?;
+
+    let timelock_secs = ctx.accounts.platform.treasury_timelock_secs;
+    let shelter = &mut ctx.accounts.shelter;
+    let history = &mut ctx.accounts.treasury_history;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Propose the change if it is not already waiting for the timelock
+    if timelock_secs > 0 {
+        if history.pending_treasury != treasury {
+            history.pending_treasury = treasury;
+            history.pending_treasury_eta = now.checked_add(timelock_secs).ok_or(error::WoofiError::MathOverflow)?;
+
+            emit!(TreasuryUpdateProposed {
+                shelter: shelter.key(),
+                current_treasury: shelter.treasury,
+                pending_treasury: treasury,
+                eta: history.pending_treasury_eta,
+            });
+            msg!("Treasury change to {} proposed, applies after {}", treasury, history.pending_treasury_eta);
+
+            return Ok(());
+        }
+
+        if now < history.pending_treasury_eta {
+            return err!(error::WoofiError::TimelockNotExpired);
+        }
+    }
+
+    // Apply the change and record the previous treasury
+    let old_treasury = shelter.treasury;
+    shelter.open_treasury(treasury, ctx.accounts.new_treasury.lamports());
+    history.pending_treasury = Pubkey::default();
+    history.pending_treasury_eta = 0;
+    history.push(old_treasury, now);
+
+    emit!(TreasuryUpdated {
+        shelter: shelter.key(),
+        old_treasury,
+        new_treasury: treasury,
+        timestamp: now,
+    });
+    msg!("Treasury of shelter {} changed from {} to {}", shelter.name, old_treasury, treasury);
+
+	Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	pub struct VerifyFoster<'info> {
+		#[account(
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+		)]
+		pub foster: Account<'info, Foster>,
+	}
+
+/// Mark a foster volunteer as verified so dogs can be assigned to them
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` foster: [Foster] The foster to verify
+pub fn handler(
+	ctx: Context<VerifyFoster>,
+) -> Result<()> {
+    // Refuse while dog management is paused
+    ctx.accounts.platform.check_not_paused(PAUSE_DOG_MANAGEMENT)?;
+
+    let foster = &mut ctx.accounts.foster;
+    foster.verified = true;
+    foster.verified_at = Clock::get()?.unix_timestamp;
+
+    msg!("Verified foster: {}", foster.wallet);
+
+	Ok(())
+}
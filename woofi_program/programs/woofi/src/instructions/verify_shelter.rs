@@ -0,0 +1,49 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+	#[derive(Accounts)]
+	#[instruction(
+		verification_hash: [u8; 32],
+	)]
+	pub struct VerifyShelter<'info> {
+		#[account(
+            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+        )]
+		pub admin: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"platform",
+			],
+			bump,
+		)]
+		pub platform: Account<'info, Platform>,
+
+		#[account(
+			mut,
+		)]
+		pub shelter: Account<'info, Shelter>,
+	}
+
+/// Mark a shelter as verified after its onboarding documents were reviewed
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter to verify
+///
+/// Data:
+/// - verification_hash: [[u8; 32]] Hash of the off-chain verification documents
+pub fn handler(
+	ctx: Context<VerifyShelter>,
+	verification_hash: [u8; 32],
+) -> Result<()> {
+    let shelter = &mut ctx.accounts.shelter;
+    shelter.verified = true;
+    shelter.verification_hash = verification_hash;
+    shelter.verified_at = Clock::get()?.unix_timestamp;
+
+    msg!("Verified shelter: {}", shelter.name);
+
+	Ok(())
+}
@@ -5,14 +5,18 @@ use std::str::FromStr;
 	#[derive(Accounts)]
 	#[instruction(
 		amount: u64,
+		category: SpendingCategory,
+		invoice_hash: [u8; 32],
 	)]
 	pub struct WithdrawFunds<'info> {
 		#[account(
-            constraint = admin.key() == platform.admin @ error::WoofiError::Unauthorized
+			mut,
+            constraint = admin.key() == shelter.admin @ error::WoofiError::Unauthorized
         )]
 		pub admin: Signer<'info>,
 
 		#[account(
+			mut,
 			seeds = [
 				b"platform",
 			],
@@ -22,47 +26,132 @@ use std::str::FromStr;
 
 		#[account(
 			mut,
-            address = platform.treasury @ error::WoofiError::Unauthorized
 		)]
-		/// CHECK: Treasury address is verified against platform.treasury
+		pub shelter: Account<'info, Shelter>,
+
+		#[account(
+			mut,
+            constraint = dog.owner_shelter == shelter.key() @ error::WoofiError::ShelterMismatch
+		)]
+		pub dog: Option<Account<'info, Dog>>,
+
+		#[account(
+			mut,
+            address = shelter.treasury @ error::WoofiError::Unauthorized
+		)]
+		/// CHECK: Treasury address is verified against shelter.treasury
 		pub treasury: UncheckedAccount<'info>,
 
 		#[account(
 			mut,
+            constraint = payee.is_some()
+                || !platform.require_registered_payees
+                || dog.as_ref().is_some_and(|dog| dog.is_foster_payout(&recipient.key(), category))
+                @ error::WoofiError::RecipientNotAllowed
 		)]
-		/// CHECK: Recipient is specified by the admin
+		/// CHECK: Recipient is specified by the admin, and must be a registered payee or the dog's
+		/// foster receiving food or toys funds if the platform requires registered payees
 		pub recipient: UncheckedAccount<'info>,
+
+		#[account(
+			seeds = [
+				b"payee",
+				recipient.key().as_ref(),
+			],
+			bump,
+		)]
+		pub payee: Option<Account<'info, Payee>>,
+
+		#[account(
+			init,
+			space=8 + Withdrawal::INIT_SPACE,
+			payer=admin,
+			seeds = [
+				b"withdrawal",
+				shelter.key().as_ref(),
+				shelter.withdrawal_count.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub withdrawal: Account<'info, Withdrawal>,
+
+		pub system_program: Program<'info, System>,
 	}
 
 /// Allow admin to withdraw funds from the treasury
 ///
 /// Accounts:
-/// 0. `[signer]` admin: [AccountInfo] 
-/// 1. `[]` platform: [Platform] The platform configuration account
-/// 2. `[writable]` treasury: [AccountInfo] Treasury wallet to withdraw from
-/// 3. `[writable]` recipient: [AccountInfo] Recipient wallet to receive the funds
+/// 0. `[writable, signer]` admin: [AccountInfo] The shelter's admin wallet
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter whose treasury is withdrawn from
+/// 3. `[writable]` dog: [Dog] Optional, the dog the funds are spent on
+/// 4. `[writable]` treasury: [AccountInfo] The shelter's treasury wallet to withdraw from
+/// 5. `[writable]` recipient: [AccountInfo] Recipient wallet to receive the funds, a payee or the dog's foster
+/// 6. `[]` payee: [Payee] Optional, the recipient's payee record
+/// 7. `[writable]` withdrawal: [Withdrawal] The withdrawal record account
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - amount: [u64] Amount of SOL to withdraw
+/// - category: [SpendingCategory] What the funds are spent on
+/// - invoice_hash: [[u8; 32]] Content hash of the invoice
 pub fn handler(
 	ctx: Context<WithdrawFunds>,
 	amount: u64,
+	category: SpendingCategory,
+	invoice_hash: [u8; 32],
 ) -> Result<()> {
+    // Refuse while withdrawals are paused
+    ctx.accounts.platform.check_not_paused(PAUSE_WITHDRAWALS)?;
+
     // Validate the withdrawal amount
     if amount == 0 {
         return err!(error::WoofiError::InvalidAmount);
     }
 
+    // Transfers move with a dog and are not spending
+    if category == SpendingCategory::Transfer {
+        return err!(error::WoofiError::InvalidSpendingCategory);
+    }
+
     // Check if treasury has enough funds
     let treasury_balance = ctx.accounts.treasury.lamports();
     if treasury_balance < amount {
         return err!(error::WoofiError::InsufficientFunds);
     }
 
+    // Enforce the platform's withdrawal cap
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.shelter.record_withdrawal(&ctx.accounts.platform, now, amount)?;
+
     // Transfer SOL from treasury to recipient
     **ctx.accounts.treasury.try_borrow_mut_lamports()? -= amount;
     **ctx.accounts.recipient.try_borrow_mut_lamports()? += amount;
 
+    // Record the withdrawal
+    let shelter = &mut ctx.accounts.shelter;
+    let withdrawal = &mut ctx.accounts.withdrawal;
+    withdrawal.shelter = shelter.key();
+    withdrawal.dog = ctx.accounts.dog.as_ref().map(|dog| dog.key());
+    withdrawal.amount = amount;
+    withdrawal.recipient = ctx.accounts.recipient.key();
+    withdrawal.timestamp = now;
+    withdrawal.category = category;
+    withdrawal.invoice_hash = invoice_hash;
+    withdrawal.index = shelter.withdrawal_count;
+
+    shelter.withdrawal_count = shelter.withdrawal_count.checked_add(1).ok_or(error::WoofiError::MathOverflow)?;
+    shelter.total_withdrawn = shelter.total_withdrawn.checked_add(amount).ok_or(error::WoofiError::MathOverflow)?;
+
+    // Update platform's total withdrawals
+    let platform = &mut ctx.accounts.platform;
+    platform.total_withdrawn = platform.total_withdrawn.checked_add(amount).ok_or(error::WoofiError::MathOverflow)?;
+
+    // Update the linked dog's spending
+    if let Some(dog) = &mut ctx.accounts.dog {
+        dog.total_withdrawn = dog.total_withdrawn.checked_add(amount).ok_or(error::WoofiError::MathOverflow)?;
+    }
+
     msg!("Withdrawn {} lamports from treasury to recipient", amount);
 	
 	Ok(())
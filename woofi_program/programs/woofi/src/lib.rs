@@ -1,6 +1,7 @@
 
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -8,6 +9,7 @@ use anchor_lang::prelude::*;
 use std::str::FromStr;
 
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -33,42 +35,43 @@ pub mod woofi {
 /// Add a new dog to the platform
 ///
 /// Accounts:
-/// 0. `[signer]` admin: [AccountInfo] 
+/// 0. `[writable, signer]` admin: [AccountInfo] The shelter's admin wallet
 /// 1. `[writable]` platform: [Platform] The platform configuration account
-/// 2. `[writable]` dog: [Dog] The dog account to be created
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 2. `[writable]` shelter: [Shelter] The shelter the dog belongs to
+/// 3. `[writable]` dog: [Dog] The dog account to be created
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] Name of the dog
-/// - age: [u8] Age of the dog in years
+/// - intake_date: [i64] When the shelter took the dog in, 0 for now
+/// - birth_date: [i64] Estimated date of birth, 0 when unknown
 /// - image_url: [String] URL to the dog's image
-/// - story: [String] The rescue story of the dog
-/// - needs_food: [bool] Whether the dog needs food
-/// - needs_toys: [bool] Whether the dog needs toys
-/// - needs_medical: [bool] Whether the dog needs medical support
-/// - needs_shelter: [bool] Whether the dog needs shelter
-/// - needs_other: [String] Other specific needs
-	pub fn add_dog(ctx: Context<AddDog>, name: String, age: u8, image_url: String, story: String, needs_food: bool, needs_toys: bool, needs_medical: bool, needs_shelter: bool, needs_other: String) -> Result<()> {
-		add_dog::handler(ctx, name, age, image_url, story, needs_food, needs_toys, needs_medical, needs_shelter, needs_other)
+/// - story: [String] The rescue story of the dog, may be empty with a metadata document
+/// - profile: [DogProfile] Breed, sex, size, weight, medical status and location
+/// - metadata_uri: [String] Optional JSON document with the full story, photos and vet notes
+/// - metadata_hash: [[u8; 32]] SHA-256 of the metadata document, zero without one
+	pub fn add_dog(ctx: Context<AddDog>, name: String, intake_date: i64, birth_date: i64, image_url: String, story: String, profile: DogProfile, metadata_uri: String, metadata_hash: [u8; 32]) -> Result<()> {
+		add_dog::handler(ctx, name, intake_date, birth_date, image_url, story, profile, metadata_uri, metadata_hash)
 	}
 
 /// Update a dog's information
 ///
 /// Accounts:
 /// 0. `[signer]` admin: [AccountInfo] 
-/// 1. `[writable]` dog: [Dog] The dog account to be updated
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` dog: [Dog] The dog account to be updated
 ///
 /// Data:
 /// - image_url: [String] Updated URL to the dog's image
-/// - story: [String] Updated rescue story of the dog
-/// - needs_food: [bool] Whether the dog needs food
-/// - needs_toys: [bool] Whether the dog needs toys
-/// - needs_medical: [bool] Whether the dog needs medical support
-/// - needs_shelter: [bool] Whether the dog needs shelter
-/// - needs_other: [String] Other specific needs
+/// - story: [String] Updated rescue story of the dog, may be empty with a metadata document
 /// - active: [bool] Whether the dog is currently active on the platform
-	pub fn update_dog(ctx: Context<UpdateDog>, image_url: String, story: String, needs_food: bool, needs_toys: bool, needs_medical: bool, needs_shelter: bool, needs_other: String, active: bool) -> Result<()> {
-		update_dog::handler(ctx, image_url, story, needs_food, needs_toys, needs_medical, needs_shelter, needs_other, active)
+/// - profile: [DogProfile] Breed, sex, size, weight, medical status and location
+/// - intake_date: [i64] Corrected date the shelter took the dog in
+/// - birth_date: [i64] Corrected estimated date of birth, 0 when unknown
+/// - metadata_uri: [String] Optional JSON document with the full story, photos and vet notes
+/// - metadata_hash: [[u8; 32]] SHA-256 of the metadata document, zero without one
+	pub fn update_dog(ctx: Context<UpdateDog>, image_url: String, story: String, active: bool, profile: DogProfile, intake_date: i64, birth_date: i64, metadata_uri: String, metadata_hash: [u8; 32]) -> Result<()> {
+		update_dog::handler(ctx, image_url, story, active, profile, intake_date, birth_date, metadata_uri, metadata_hash)
 	}
 
 /// Make a donation to a specific dog
@@ -76,31 +79,629 @@ pub mod woofi {
 /// Accounts:
 /// 0. `[writable, signer]` donor: [AccountInfo] 
 /// 1. `[writable]` platform: [Platform] The platform configuration account
-/// 2. `[writable]` dog: [Dog] The dog receiving the donation
-/// 3. `[writable]` donation: [Donation] The donation record account
-/// 4. `[writable]` treasury: [AccountInfo] Treasury wallet to receive the donation
-/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 2. `[writable]` shelter: [Shelter] The shelter caring for the dog
+/// 3. `[writable]` dog: [Dog] The dog receiving the donation
+/// 4. `[writable]` donation: [Donation] The donation record account
+/// 5. `[writable]` treasury: [AccountInfo] The shelter's treasury wallet to receive the donation
+/// 6. `[]` moderation: [ModerationConfig] The moderation rules for donation messages, if set
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - amount: [u64] Amount of SOL to donate
 /// - message: [String] Optional message from the donor
 /// - timestamp: [i64] Unix timestamp of when the donation was made
-	pub fn donate(ctx: Context<Donate>, amount: u64, message: String, timestamp: i64) -> Result<()> {
-		donate::handler(ctx, amount, message, timestamp)
+/// - anonymous: [bool] Hide the donor's identity from public listings
+/// - need_index: [Option<u8>] Need of the dog to earmark the donation for
+	pub fn donate(ctx: Context<Donate>, amount: u64, message: String, timestamp: i64, anonymous: bool, need_index: Option<u8>) -> Result<()> {
+		donate::handler(ctx, amount, message, timestamp, anonymous, need_index)
 	}
 
 /// Allow admin to withdraw funds from the treasury
 ///
 /// Accounts:
-/// 0. `[signer]` admin: [AccountInfo] 
-/// 1. `[]` platform: [Platform] The platform configuration account
-/// 2. `[writable]` treasury: [AccountInfo] Treasury wallet to withdraw from
-/// 3. `[writable]` recipient: [AccountInfo] Recipient wallet to receive the funds
+/// 0. `[writable, signer]` admin: [AccountInfo] The shelter's admin wallet
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter whose treasury is withdrawn from
+/// 3. `[writable]` dog: [Dog] Optional, the dog the funds are spent on
+/// 4. `[writable]` treasury: [AccountInfo] The shelter's treasury wallet to withdraw from
+/// 5. `[writable]` recipient: [AccountInfo] Recipient wallet to receive the funds, a payee or the dog's foster
+/// 6. `[]` payee: [Payee] Optional, the recipient's payee record
+/// 7. `[writable]` withdrawal: [Withdrawal] The withdrawal record account
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - amount: [u64] Amount of SOL to withdraw
-	pub fn withdraw_funds(ctx: Context<WithdrawFunds>, amount: u64) -> Result<()> {
-		withdraw_funds::handler(ctx, amount)
+/// - category: [SpendingCategory] What the funds are spent on
+/// - invoice_hash: [[u8; 32]] Content hash of the invoice
+	pub fn withdraw_funds(ctx: Context<WithdrawFunds>, amount: u64, category: SpendingCategory, invoice_hash: [u8; 32]) -> Result<()> {
+		withdraw_funds::handler(ctx, amount, category, invoice_hash)
+	}
+
+/// Configure receipt NFT minting for donations
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - enabled: [bool] Whether donors may mint receipt NFTs
+/// - min_amount: [u64] Minimum donation in lamports that qualifies for a receipt
+	pub fn set_receipt_config(ctx: Context<SetReceiptConfig>, enabled: bool, min_amount: u64) -> Result<()> {
+		set_receipt_config::handler(ctx, enabled, min_amount)
+	}
+
+/// Mint a receipt NFT for a donation
+///
+/// Accounts:
+/// 0. `[writable, signer]` donor: [AccountInfo] The donor who made the donation
+/// 1. `[]` platform: [Platform] The platform configuration account, mint and update authority
+/// 2. `[]` dog: [Dog] The dog that received the donation
+/// 3. `[writable]` donation: [Donation] The donation record the receipt is minted for
+/// 4. `[writable]` mint: [AccountInfo] The receipt mint to be created
+/// 5. `[writable]` receipt_token_account: [AccountInfo] The donor's token account holding the receipt
+/// 6. `[writable]` metadata: [AccountInfo] The Metaplex metadata account of the receipt mint
+/// 7. `[]` token_program: [AccountInfo] SPL Token program
+/// 8. `[]` token_metadata_program: [AccountInfo] Metaplex Token Metadata program
+/// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 10. `[]` rent: [AccountInfo] Rent sysvar
+///
+/// Data:
+/// - uri: [String] URI of the off-chain receipt metadata JSON
+	pub fn mint_receipt(ctx: Context<MintReceipt>, uri: String) -> Result<()> {
+		mint_receipt::handler(ctx, uri)
+	}
+
+/// Grant a wallet curator rights
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` curator: [Curator] The curator record to be created
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - wallet: [Pubkey] Wallet receiving curator rights
+	pub fn add_curator(ctx: Context<AddCurator>, wallet: Pubkey) -> Result<()> {
+		add_curator::handler(ctx, wallet)
+	}
+
+/// Revoke a wallet's curator rights
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` curator: [Curator] The curator record to be closed
+	pub fn remove_curator(ctx: Context<RemoveCurator>) -> Result<()> {
+		remove_curator::handler(ctx)
+	}
+
+/// Set the moderation rules enforced on donation messages
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` moderation: [ModerationConfig] The moderation config, created on first use
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - max_urls: [u8] Maximum number of links allowed in a donation message
+/// - banned_substrings: [Vec<String>] Case-insensitive substrings rejected in donation messages
+	pub fn set_moderation_config(ctx: Context<SetModerationConfig>, max_urls: u8, banned_substrings: Vec<String>) -> Result<()> {
+		set_moderation_config::handler(ctx, max_urls, banned_substrings)
+	}
+
+/// Hide a donation message from public pages
+///
+/// Not affected by the pause flags, so abusive messages can be hidden during an incident.
+///
+/// Accounts:
+/// 0. `[signer]` moderator: [AccountInfo] The platform admin or a curator
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` curator: [Curator] (optional) The moderator's curator record
+/// 3. `[writable]` donation: [Donation] The donation whose message is hidden
+	pub fn hide_donation_message(ctx: Context<HideDonationMessage>) -> Result<()> {
+		hide_donation_message::handler(ctx)
+	}
+
+/// Edit or delete the message of a donation within the edit window
+///
+/// Accounts:
+/// 0. `[signer]` donor: [AccountInfo] The donor who made the donation
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` donation: [Donation] The donation whose message is edited
+/// 3. `[]` moderation: [ModerationConfig] The moderation rules for donation messages, if set
+///
+/// Data:
+/// - message: [String] The new message, empty to delete it
+	pub fn edit_donation_message(ctx: Context<EditDonationMessage>, message: String) -> Result<()> {
+		edit_donation_message::handler(ctx, message)
+	}
+
+/// Attach a public thank-you reply to a donation
+///
+/// Accounts:
+/// 0. `[signer]` curator_signer: [AccountInfo] The platform admin or a curator
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` curator: [Curator] (optional) The signer's curator record
+/// 3. `[writable]` donation: [Donation] The donation being replied to
+///
+/// Data:
+/// - thank_you: [String] The reply, empty to remove it
+	pub fn reply_to_donation(ctx: Context<ReplyToDonation>, thank_you: String) -> Result<()> {
+		reply_to_donation::handler(ctx, thank_you)
+	}
+
+/// Register a shelter organization on the platform. Onboarding goes through the
+/// platform admin, who names the wallet that will administer the shelter.
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The platform admin wallet
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter account to be created
+/// 3. `[]` shelter_treasury: [AccountInfo] The treasury wallet, whose balance reconciliation starts from
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] Name of the shelter
+/// - treasury: [Pubkey] Treasury wallet receiving donations to the shelter's dogs
+/// - shelter_admin: [Pubkey] Wallet administering the shelter and its dogs
+	pub fn register_shelter(ctx: Context<RegisterShelter>, name: String, treasury: Pubkey, shelter_admin: Pubkey) -> Result<()> {
+		register_shelter::handler(ctx, name, treasury, shelter_admin)
+	}
+
+/// Mark a shelter as verified after its onboarding documents were reviewed
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter to verify
+///
+/// Data:
+/// - verification_hash: [[u8; 32]] Hash of the off-chain verification documents
+	pub fn verify_shelter(ctx: Context<VerifyShelter>, verification_hash: [u8; 32]) -> Result<()> {
+		verify_shelter::handler(ctx, verification_hash)
+	}
+
+/// Revoke a shelter's verified status
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter to revoke
+	pub fn revoke_shelter(ctx: Context<RevokeShelter>) -> Result<()> {
+		revoke_shelter::handler(ctx)
+	}
+
+/// Configure whether donations to unverified shelters are accepted
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - require_verified_shelters: [bool] Reject donations to unverified shelters
+	pub fn set_shelter_policy(ctx: Context<SetShelterPolicy>, require_verified_shelters: bool) -> Result<()> {
+		set_shelter_policy::handler(ctx, require_verified_shelters)
+	}
+
+/// Pause or resume groups of instructions
+///
+/// The admin may set any combination of flags, the guardian may only add flags so a
+/// compromised guardian key cannot resume the program. Admin configuration and
+/// message moderation stay available while paused.
+///
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The platform admin or guardian
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - paused: [u8] Bitmask of `PAUSE_DONATIONS`, `PAUSE_WITHDRAWALS` and `PAUSE_DOG_MANAGEMENT`
+	pub fn set_pause(ctx: Context<SetPause>, paused: u8) -> Result<()> {
+		set_pause::handler(ctx, paused)
+	}
+
+/// Set the guardian key allowed to pause the program
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - guardian: [Pubkey] The new guardian, the default pubkey to remove it
+	pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+		set_guardian::handler(ctx, guardian)
+	}
+
+/// Change the treasury receiving donations to a shelter's dogs
+///
+/// Without a platform timelock the change applies immediately. With a timelock the
+/// first call proposes the treasury, and calling again with the same treasury once
+/// the timelock has expired applies it. Proposing another treasury restarts the delay.
+/// Funds already held by the previous treasury stay there, and reconciliation
+/// starts over from the new treasury's balance.
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The shelter admin
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter whose treasury changes
+/// 3. `[writable]` treasury_history: [TreasuryHistory] Pending and previous treasuries of the shelter, created on first use
+/// 4. `[]` new_treasury: [AccountInfo] The new treasury wallet, whose balance reconciliation starts from
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - treasury: [Pubkey] The new treasury wallet
+	pub fn update_treasury(ctx: Context<UpdateTreasury>, treasury: Pubkey) -> Result<()> {
+		update_treasury::handler(ctx, treasury)
+	}
+
+/// Set the delay applied to shelter treasury changes
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - timelock_secs: [i64] Seconds between proposing and applying a treasury change
+	pub fn set_treasury_timelock(ctx: Context<SetTreasuryTimelock>, timelock_secs: i64) -> Result<()> {
+		set_treasury_timelock::handler(ctx, timelock_secs)
+	}
+
+/// Register a wallet as a payee for withdrawals
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` payee: [Payee] The payee record to be created
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - wallet: [Pubkey] Wallet of the payee
+/// - label: [String] Name of the payee, e.g. the vet clinic
+	pub fn add_payee(ctx: Context<AddPayee>, wallet: Pubkey, label: String) -> Result<()> {
+		add_payee::handler(ctx, wallet, label)
+	}
+
+/// Remove a wallet from the payee registry
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` payee: [Payee] The payee record to be closed
+	pub fn remove_payee(ctx: Context<RemovePayee>) -> Result<()> {
+		remove_payee::handler(ctx)
+	}
+
+/// Configure whether withdrawals are restricted to registered payees
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - require_registered_payees: [bool] Only allow withdrawals to registered payees
+	pub fn set_payee_policy(ctx: Context<SetPayeePolicy>, require_registered_payees: bool) -> Result<()> {
+		set_payee_policy::handler(ctx, require_registered_payees)
+	}
+
+/// Cap how much each shelter can withdraw per period
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+///
+/// Data:
+/// - max_withdraw_per_period: [u64] Lamports each shelter may withdraw per period, 0 for no limit
+/// - withdraw_period_secs: [i64] Length of the withdrawal period in seconds
+	pub fn set_withdraw_limit(ctx: Context<SetWithdrawLimit>, max_withdraw_per_period: u64, withdraw_period_secs: i64) -> Result<()> {
+		set_withdraw_limit::handler(ctx, max_withdraw_per_period, withdraw_period_secs)
+	}
+
+/// Compare a shelter treasury's lamports to its recorded donations and withdrawals
+///
+/// Accounts:
+/// 0. `[]` shelter: [Shelter] The shelter to reconcile
+/// 1. `[]` treasury: [AccountInfo] The shelter's treasury wallet
+	pub fn reconcile(ctx: Context<Reconcile>) -> Result<Reconciliation> {
+		reconcile::handler(ctx)
+	}
+
+/// Migrate the platform account to the current layout version
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The platform admin
+/// 1. `[writable]` platform: [Platform] The platform configuration account
+/// 2. `[]` system_program: [AccountInfo] Auto-generated, for account reallocation
+	pub fn migrate_platform(ctx: Context<MigratePlatform>) -> Result<()> {
+		migrate_platform::handler(ctx)
+	}
+
+/// Migrate a dog account to the current layout version
+///
+/// Accounts:
+/// 0. `[writable, signer]` payer: [AccountInfo] Pays for the larger account
+/// 1. `[]` platform: [Platform] The platform configuration account, migrated first
+/// 2. `[writable]` dog: [Dog] The dog account to migrate
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account reallocation
+	pub fn migrate_dog(ctx: Context<MigrateDog>) -> Result<()> {
+		migrate_dog::handler(ctx)
+	}
+
+/// Migrate a donation account to the current layout version
+///
+/// Accounts:
+/// 0. `[writable, signer]` payer: [AccountInfo] Pays for the larger account
+/// 1. `[]` platform: [Platform] The platform configuration account, migrated first
+/// 2. `[writable]` donation: [Donation] The donation account to migrate
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account reallocation
+	pub fn migrate_donation(ctx: Context<MigrateDonation>) -> Result<()> {
+		migrate_donation::handler(ctx)
+	}
+
+/// Hand a dog from before shelters to a shelter, together with its unspent balance
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The platform admin
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` shelter: [Shelter] The shelter taking the dog in
+/// 3. `[writable]` dog: [Dog] The migrated dog, not yet assigned to a shelter
+/// 4. `[writable]` legacy_treasury: [AccountInfo] The platform treasury holding the dog's donations
+/// 5. `[writable]` treasury: [AccountInfo] The shelter's treasury wallet
+	pub fn assign_legacy_dog(ctx: Context<AssignLegacyDog>) -> Result<()> {
+		assign_legacy_dog::handler(ctx)
+	}
+
+/// Append an image or video to a dog's gallery
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` dog: [Dog] The dog the media shows
+/// 3. `[writable]` dog_media: [DogMedia] The dog's gallery, created on first use
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - kind: [MediaKind] Whether the media is an image or a video
+/// - uri: [String] HTTPS, IPFS or Arweave location of the file
+/// - content_hash: [[u8; 32]] SHA-256 of the file
+/// - caption: [String] Short description shown with the media
+	pub fn add_dog_media(ctx: Context<AddDogMedia>, kind: MediaKind, uri: String, content_hash: [u8; 32], caption: String) -> Result<()> {
+		add_dog_media::handler(ctx, kind, uri, content_hash, caption)
+	}
+
+/// Remove an entry from a dog's gallery, keeping the order of the others
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` dog: [Dog] The dog the gallery belongs to
+/// 3. `[writable]` dog_media: [DogMedia] The dog's gallery
+///
+/// Data:
+/// - index: [u8] Position of the entry to remove
+	pub fn remove_dog_media(ctx: Context<RemoveDogMedia>, index: u8) -> Result<()> {
+		remove_dog_media::handler(ctx, index)
+	}
+
+/// Change the display order of a dog's gallery
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` dog: [Dog] The dog the gallery belongs to
+/// 3. `[writable]` dog_media: [DogMedia] The dog's gallery
+///
+/// Data:
+/// - order: [Vec<u8>] Current positions of the entries, listed in their new order
+	pub fn reorder_dog_media(ctx: Context<ReorderDogMedia>, order: Vec<u8>) -> Result<()> {
+		reorder_dog_media::handler(ctx, order)
+	}
+
+/// Add a costed need supporters can earmark donations for
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` dog: [Dog] The dog with the need
+///
+/// Data:
+/// - category: [SpendingCategory] What the funds will be spent on
+/// - description: [String] What exactly is needed, e.g. "X-ray"
+/// - target: [u64] Lamports needed
+	pub fn add_need(ctx: Context<AddNeed>, category: SpendingCategory, description: String, target: u64) -> Result<()> {
+		add_need::handler(ctx, category, description, target)
+	}
+
+/// Mark a need as fulfilled before its target is reached
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` dog: [Dog] The dog with the need
+///
+/// Data:
+/// - index: [u8] Position of the need in the dog's list
+	pub fn close_need(ctx: Context<CloseNeed>, index: u8) -> Result<()> {
+		close_need::handler(ctx, index)
+	}
+
+/// Append a treatment to a dog's medical timeline
+///
+/// Accounts:
+/// 0. `[writable, signer]` curator_wallet: [AccountInfo] The dog's admin, the platform admin or a curator
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` curator: [Curator] (optional) The signer's curator record
+/// 3. `[writable]` dog: [Dog] The treated dog
+/// 4. `[]` clinic: [Payee] The registered payee that treated the dog
+/// 5. `[]` withdrawal: [Withdrawal] (optional) The medical withdrawal that paid the clinic
+/// 6. `[writable]` medical_record: [MedicalRecord] The record account to be created
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - date: [i64] Unix timestamp of the treatment
+/// - procedure: [String] What was done, e.g. "Rabies vaccination"
+/// - cost: [u64] Lamports the treatment cost
+/// - document_hash: [[u8; 32]] Content hash of the vet report or invoice
+	pub fn add_medical_record(ctx: Context<AddMedicalRecord>, date: i64, procedure: String, cost: u64, document_hash: [u8; 32]) -> Result<()> {
+		add_medical_record::handler(ctx, date, procedure, cost, document_hash)
+	}
+
+/// Post a progress update to a dog's news feed
+///
+/// Accounts:
+/// 0. `[writable, signer]` author: [AccountInfo] The dog's admin, the platform admin or a curator
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` curator: [Curator] (optional) The author's curator record
+/// 3. `[writable]` dog: [Dog] The dog the update is about
+/// 4. `[writable]` dog_update: [DogUpdate] The update account to be created
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - title: [String] Headline of the update
+/// - body: [String] Inline text of the update, may be empty with a body hash
+/// - body_hash: [[u8; 32]] SHA-256 of an off-chain body, zero for inline bodies
+/// - media_uri: [String] Optional photo or video of the update
+	pub fn post_dog_update(ctx: Context<PostDogUpdate>, title: String, body: String, body_hash: [u8; 32], media_uri: String) -> Result<()> {
+		post_dog_update::handler(ctx, title, body, body_hash, media_uri)
+	}
+
+/// Apply to adopt a dog
+///
+/// Accounts:
+/// 0. `[writable, signer]` applicant: [AccountInfo] The wallet applying to adopt
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` dog: [Dog] The dog to adopt
+/// 3. `[writable]` application: [AdoptionApplication] The application account to be created
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - contact_hash: [[u8; 32]] Hash of the applicant's contact details, shared off-chain
+	pub fn submit_adoption_application(ctx: Context<SubmitAdoptionApplication>, contact_hash: [u8; 32]) -> Result<()> {
+		submit_adoption_application::handler(ctx, contact_hash)
+	}
+
+/// Move an adoption application forward: take it under review, then approve or
+/// reject it. Approving marks the dog as adopted, which stops donations to it.
+///
+/// Accounts:
+/// 0. `[signer]` reviewer: [AccountInfo] The platform admin or a curator
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` curator: [Curator] (optional) The reviewer's curator record
+/// 3. `[writable]` dog: [Dog] The dog applied for
+/// 4. `[writable]` application: [AdoptionApplication] The application to review
+///
+/// Data:
+/// - status: [ApplicationStatus] UnderReview, Approved or Rejected
+	pub fn review_adoption_application(ctx: Context<ReviewAdoptionApplication>, status: ApplicationStatus) -> Result<()> {
+		review_adoption_application::handler(ctx, status)
+	}
+
+/// Register as a foster volunteer, pending verification by the platform
+///
+/// Accounts:
+/// 0. `[writable, signer]` wallet: [AccountInfo] The volunteer's wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` foster: [Foster] The foster record to be created
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - region: [String] Where the foster lives
+/// - capacity: [u8] Number of dogs the foster can care for at once
+	pub fn register_foster(ctx: Context<RegisterFoster>, region: String, capacity: u8) -> Result<()> {
+		register_foster::handler(ctx, region, capacity)
+	}
+
+/// Mark a foster volunteer as verified so dogs can be assigned to them
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo]
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` foster: [Foster] The foster to verify
+	pub fn verify_foster(ctx: Context<VerifyFoster>) -> Result<()> {
+		verify_foster::handler(ctx)
+	}
+
+/// Place a dog with a verified foster volunteer
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The dog's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` dog: [Dog] The dog moving in with the foster
+/// 3. `[writable]` foster: [Foster] The foster taking the dog in
+///
+/// Data:
+/// - allow_payouts: [bool] Whether food and toys funds may be withdrawn straight to the foster
+	pub fn assign_foster(ctx: Context<AssignFoster>, allow_payouts: bool) -> Result<()> {
+		assign_foster::handler(ctx, allow_payouts)
+	}
+
+/// End a dog's stay with its foster, by the dog's admin or the foster themselves
+///
+/// Accounts:
+/// 0. `[signer]` signer: [AccountInfo] The dog's admin or the foster's wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` dog: [Dog] The dog leaving the foster
+/// 3. `[writable]` foster: [Foster] The dog's current foster
+	pub fn end_foster(ctx: Context<EndFoster>) -> Result<()> {
+		end_foster::handler(ctx)
+	}
+
+/// Propose moving a dog to another shelter; the move happens once the
+/// destination shelter's admin accepts it
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The source shelter's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` from_shelter: [Shelter] The shelter currently owning the dog
+/// 3. `[]` to_shelter: [Shelter] The shelter the dog moves to
+/// 4. `[]` dog: [Dog] The dog to transfer
+/// 5. `[writable]` transfer: [DogTransfer] The pending transfer to be created
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+	pub fn propose_dog_transfer(ctx: Context<ProposeDogTransfer>) -> Result<()> {
+		propose_dog_transfer::handler(ctx)
+	}
+
+/// Accept a proposed transfer, moving the dog and its unspent balance to the
+/// destination shelter.
+///
+/// The balance is booked as transferred out of the source shelter and into the
+/// destination shelter, so both still reconcile. It is neither a donation nor
+/// spending: the platform totals and the source shelter's withdrawal cap are
+/// untouched, and the source shelter's `Transfer` withdrawal record only keeps
+/// the move in its history.
+/// A foster placement belongs to the source shelter and ends with the transfer.
+///
+/// The dog account keeps its address, which remains derived from the source
+/// shelter. Donations, withdrawals, medical records and updates all point at
+/// that address, so re-seeding the dog would orphan its history; instructions
+/// check `owner_shelter` instead of the seeds.
+///
+/// Accounts:
+/// 0. `[writable, signer]` admin: [AccountInfo] The destination shelter's admin wallet
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[writable]` from_shelter: [Shelter] The shelter currently owning the dog
+/// 3. `[writable]` to_shelter: [Shelter] The shelter the dog moves to
+/// 4. `[writable]` dog: [Dog] The dog to transfer
+/// 5. `[writable]` foster: [Foster] Optional, the dog's foster, required while the dog is fostered
+/// 6. `[writable]` from_treasury: [AccountInfo] The source shelter's treasury wallet
+/// 7. `[writable]` to_treasury: [AccountInfo] The destination shelter's treasury wallet
+/// 8. `[writable]` proposer: [AccountInfo] The wallet that proposed the transfer
+/// 9. `[writable]` transfer: [DogTransfer] The pending transfer, closed
+/// 10. `[writable]` withdrawal: [Withdrawal] The source shelter's `Transfer` record of the balance
+/// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+	pub fn accept_dog_transfer(ctx: Context<AcceptDogTransfer>) -> Result<()> {
+		accept_dog_transfer::handler(ctx)
+	}
+
+/// Withdraw or decline a proposed transfer, by either shelter's admin
+///
+/// Accounts:
+/// 0. `[signer]` admin: [AccountInfo] The proposer or the destination shelter's admin
+/// 1. `[]` platform: [Platform] The platform configuration account
+/// 2. `[]` to_shelter: [Shelter] The shelter the dog would have moved to
+/// 3. `[writable]` proposer: [AccountInfo] The wallet that proposed the transfer
+/// 4. `[writable]` transfer: [DogTransfer] The pending transfer, closed
+	pub fn cancel_dog_transfer(ctx: Context<CancelDogTransfer>) -> Result<()> {
+		cancel_dog_transfer::handler(ctx)
 	}
 
 
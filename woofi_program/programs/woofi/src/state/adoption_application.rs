@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ApplicationStatus {
+	Submitted,
+	UnderReview,
+	Approved,
+	Rejected,
+}
+
+/// A request to adopt a dog, PDA `[b"adoption", dog, applicant]`
+#[account]
+pub struct AdoptionApplication {
+	pub dog: Pubkey,
+	pub applicant: Pubkey,
+	/// Hash of the applicant's contact details, which are shared off-chain
+	pub contact_hash: [u8; 32],
+	pub status: ApplicationStatus,
+	pub submitted_at: i64,
+	/// Curator or admin who last changed the status, default until reviewed
+	pub reviewed_by: Pubkey,
+	pub reviewed_at: i64,
+}
+
+impl AdoptionApplication {
+	/// Move to `status`: submitted applications go under review or are
+	/// rejected, and only applications under review can be approved
+	pub fn transition(&mut self, status: ApplicationStatus, reviewer: Pubkey, now: i64) -> Result<()> {
+		let allowed = matches!(
+			(self.status, status),
+			(ApplicationStatus::Submitted, ApplicationStatus::UnderReview)
+				| (ApplicationStatus::Submitted, ApplicationStatus::Rejected)
+				| (ApplicationStatus::UnderReview, ApplicationStatus::Approved)
+				| (ApplicationStatus::UnderReview, ApplicationStatus::Rejected)
+		);
+		if !allowed {
+			return err!(crate::error::WoofiError::InvalidApplicationStatus);
+		}
+		self.status = status;
+		self.reviewed_by = reviewer;
+		self.reviewed_at = now;
+		Ok(())
+	}
+}
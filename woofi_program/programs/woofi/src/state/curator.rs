@@ -0,0 +1,16 @@
+
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Curator {
+	pub wallet: Pubkey,
+	pub added_by: Pubkey,
+}
+
+impl Curator {
+	/// Whether `signer` may curate content: the platform admin, or a wallet with
+	/// a `Curator` record (the record's seeds are checked by the instruction).
+	pub fn is_authorized(signer: &Pubkey, platform: &crate::Platform, curator: Option<&Curator>) -> bool {
+		*signer == platform.admin || curator.is_some_and(|curator| curator.wallet == *signer)
+	}
+}
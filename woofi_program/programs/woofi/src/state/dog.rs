@@ -1,8 +1,297 @@
 
 use anchor_lang::prelude::*;
 
+use crate::SpendingCategory;
+
 #[account]
+#[derive(Default)]
 pub struct Dog {
+	/// Layout version, see `Dog::VERSION`
+	pub version: u8,
+	pub name: String,
+	/// Age in years when the dog was added, superseded by `birth_date`
+	pub age: u8,
+	pub image_url: String,
+	pub story: String,
+	/// Whether an open need of the category exists, derived from `needs` for readers
+	/// of the older layouts. Migrated dogs get an open need for each flag.
+	pub needs_food: bool,
+	pub needs_toys: bool,
+	pub needs_medical: bool,
+	pub needs_shelter: bool,
+	/// Description of the first open `Other` need, empty if there is none
+	pub needs_other: String,
+	pub total_donations: u64,
+	pub admin: Pubkey,
+	pub active: bool,
+	pub owner_shelter: Pubkey,
+	/// Lamports withdrawn by the shelter with this dog linked
+	pub total_withdrawn: u64,
+	/// Structured data adopters and donors can filter on
+	pub profile: DogProfile,
+	/// Unix timestamp the shelter took the dog in
+	pub intake_date: i64,
+	/// Estimated unix timestamp of birth, 0 when unknown
+	pub birth_date: i64,
+	/// Optional JSON document with the full story, photos and vet notes
+	pub metadata_uri: String,
+	/// SHA-256 of the metadata document, zero when there is none
+	pub metadata_hash: [u8; 32],
+	/// Costed needs supporters can earmark donations for.
+	/// Needs are never removed so donations can refer to them by index.
+	pub needs: Vec<Need>,
+	/// Number of `MedicalRecord` accounts, the seed of the next one
+	pub medical_record_count: u64,
+	/// Number of `DogUpdate` posts, the seed of the next one
+	pub update_count: u64,
+	/// Where the dog is in the adoption process
+	pub lifecycle: DogLifecycle,
+	/// Wallet of the foster volunteer currently caring for the dog
+	pub foster: Option<Pubkey>,
+	/// Whether food and toys funds may be paid straight to the foster's wallet
+	pub foster_payouts: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DogLifecycle {
+	/// Open for donations and adoption applications
+	#[default]
+	Available,
+	/// An application was approved; donations and new applications are refused
+	Adopted,
+}
+
+/// Something the dog needs funded, such as "X-ray: 2 SOL"
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Need {
+	pub category: SpendingCategory,
+	pub description: String,
+	/// Lamports needed, 0 for needs carried over from the original flags, which
+	/// stay open until the shelter closes them
+	pub target: u64,
+	/// Lamports donated towards this need so far
+	pub raised: u64,
+	/// Set once `raised` reaches `target` or the shelter closes the need
+	pub fulfilled: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DogSex {
+	#[default]
+	Unknown,
+	Male,
+	Female,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DogSize {
+	#[default]
+	Unknown,
+	Small,
+	Medium,
+	Large,
+	ExtraLarge,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum VaccinationStatus {
+	#[default]
+	Unknown,
+	NotVaccinated,
+	Partial,
+	UpToDate,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SterilizationStatus {
+	#[default]
+	Unknown,
+	Intact,
+	Sterilized,
+}
+
+/// Structured dog data, `Unknown`, empty and zero values mean not recorded
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct DogProfile {
+	pub breed: String,
+	pub sex: DogSex,
+	pub size: DogSize,
+	pub weight_grams: u32,
+	pub vaccination: VaccinationStatus,
+	pub sterilization: SterilizationStatus,
+	/// City or region where the dog can be met
+	pub location: String,
+}
+
+impl DogProfile {
+	pub fn validate(&self) -> Result<()> {
+		if self.breed.len() > crate::MAX_BREED_LEN
+			|| self.location.len() > crate::MAX_LOCATION_LEN
+			|| self.weight_grams > crate::MAX_DOG_WEIGHT_GRAMS
+		{
+			return err!(crate::error::WoofiError::InvalidDogProfile);
+		}
+		Ok(())
+	}
+}
+
+impl Dog {
+	/// Current layout version. Layouts are append-only: new fields go at the end and
+	/// `migrate_dog` grows older accounts in place.
+	pub const VERSION: u8 = 1;
+
+	/// Size allocated for dog accounts, the `space` of `add_dog`
+	pub const LEN: usize = 3256;
+
+	/// Size of dog accounts created before the version header, the original `space` of `add_dog`
+	pub const V0_LEN: usize = 1420;
+
+	/// Decode an account of any layout version, upgrading older layouts in memory
+	pub fn try_deserialize_versioned(data: &[u8]) -> Result<Self> {
+		if !data.starts_with(Self::DISCRIMINATOR) {
+			return err!(ErrorCode::AccountDiscriminatorMismatch);
+		}
+		if data.len() == Self::V0_LEN {
+			return Ok(DogV0::deserialize(&mut &data[8..])?.into());
+		}
+		Self::try_deserialize(&mut &data[..])
+	}
+
+	/// Whether a withdrawal of `category` funds may go straight to `recipient` as
+	/// the dog's foster, without the recipient being a registered payee
+	pub fn is_foster_payout(&self, recipient: &Pubkey, category: SpendingCategory) -> bool {
+		self.foster_payouts
+			&& self.foster == Some(*recipient)
+			&& matches!(category, SpendingCategory::Food | SpendingCategory::Toys)
+	}
+
+	/// Refuse donations and applications once the dog has been adopted
+	pub fn check_available(&self) -> Result<()> {
+		if self.lifecycle != DogLifecycle::Available {
+			return err!(crate::error::WoofiError::DogNotAvailable);
+		}
+		Ok(())
+	}
+
+	/// Append a need, returning its index
+	pub fn add_need(&mut self, category: SpendingCategory, description: String, target: u64) -> Result<u8> {
+		if description.trim().is_empty() || description.len() > crate::MAX_NEED_DESCRIPTION_LEN || target == 0 {
+			return err!(crate::error::WoofiError::InvalidNeed);
+		}
+		if category == SpendingCategory::Transfer {
+			return err!(crate::error::WoofiError::InvalidSpendingCategory);
+		}
+		if self.needs.len() >= crate::MAX_DOG_NEEDS {
+			return err!(crate::error::WoofiError::DogNeedsFull);
+		}
+		self.needs.push(Need { category, description, target, raised: 0, fulfilled: false });
+		self.sync_need_flags();
+		Ok((self.needs.len() - 1) as u8)
+	}
+
+	/// Mark a need as fulfilled before its target is reached
+	pub fn close_need(&mut self, index: u8) -> Result<()> {
+		let need = self.need_mut(index)?;
+		if need.fulfilled {
+			return err!(crate::error::WoofiError::NeedFulfilled);
+		}
+		need.fulfilled = true;
+		self.sync_need_flags();
+		Ok(())
+	}
+
+	/// Recompute the legacy needs flags from the open needs
+	pub fn sync_need_flags(&mut self) {
+		let open = |category: SpendingCategory| {
+			self.needs.iter().any(|need| !need.fulfilled && need.category == category)
+		};
+		self.needs_food = open(SpendingCategory::Food);
+		self.needs_toys = open(SpendingCategory::Toys);
+		self.needs_medical = open(SpendingCategory::Medical);
+		self.needs_shelter = open(SpendingCategory::Shelter);
+		self.needs_other = self.needs
+			.iter()
+			.find(|need| !need.fulfilled && need.category == SpendingCategory::Other)
+			.map(|need| need.description.clone())
+			.unwrap_or_default();
+	}
+
+	pub fn need_mut(&mut self, index: u8) -> Result<&mut Need> {
+		self.needs.get_mut(index as usize).ok_or(error!(crate::error::WoofiError::InvalidNeedIndex))
+	}
+
+	/// Earmark `amount` for an open need, fulfilling it once the target is reached
+	pub fn record_need_donation(&mut self, index: u8, amount: u64) -> Result<()> {
+		let need = self.need_mut(index)?;
+		if need.fulfilled {
+			return err!(crate::error::WoofiError::NeedFulfilled);
+		}
+		need.raised = need.raised.checked_add(amount).ok_or(crate::error::WoofiError::MathOverflow)?;
+		need.fulfilled = need.target != 0 && need.raised >= need.target;
+		self.sync_need_flags();
+		Ok(())
+	}
+
+	/// Store the story inline, as a metadata document reference, or both. A
+	/// document is referenced by a supported URI together with its SHA-256.
+	pub fn set_story(&mut self, story: String, metadata_uri: String, metadata_hash: [u8; 32]) -> Result<()> {
+		let valid_metadata = if metadata_uri.is_empty() {
+			metadata_hash == [0; 32]
+		} else {
+			crate::is_supported_uri(&metadata_uri)
+				&& metadata_uri.len() <= crate::MAX_METADATA_URI_LEN
+				&& metadata_hash != [0; 32]
+		};
+		if !valid_metadata {
+			return err!(crate::error::WoofiError::InvalidDogMetadata);
+		}
+		if story.trim().is_empty() && metadata_uri.is_empty() {
+			return err!(crate::error::WoofiError::InvalidDogStory);
+		}
+		self.story = story;
+		self.metadata_uri = metadata_uri;
+		self.metadata_hash = metadata_hash;
+		Ok(())
+	}
+
+	/// Validate and store the intake and birth dates. `age` is kept in sync for
+	/// readers of the older layouts, and left as is while the birth date is
+	/// unknown so migrated dogs keep their recorded age.
+	pub fn set_dates(&mut self, intake_date: i64, birth_date: i64, now: i64) -> Result<()> {
+		if intake_date <= 0
+			|| intake_date > now
+			|| birth_date < 0
+			|| birth_date > intake_date
+			|| (birth_date != 0 && intake_date - birth_date > crate::MAX_DOG_AGE_YEARS * crate::SECONDS_PER_YEAR)
+		{
+			return err!(crate::error::WoofiError::InvalidDogDates);
+		}
+		self.intake_date = intake_date;
+		self.birth_date = birth_date;
+		if birth_date != 0 {
+			self.age = ((intake_date - birth_date) / crate::SECONDS_PER_YEAR) as u8;
+		}
+		Ok(())
+	}
+
+	/// Age in whole years at `now`, falling back to the age recorded at intake for
+	/// dogs without a birth date
+	pub fn age_years(&self, now: i64) -> Option<u8> {
+		if self.birth_date != 0 {
+			return u8::try_from(now.saturating_sub(self.birth_date).max(0) / crate::SECONDS_PER_YEAR).ok();
+		}
+		(self.age != 0).then_some(self.age)
+	}
+
+	/// Whether `data` holds an older layout that must be migrated before use
+	pub fn is_outdated(data: &[u8]) -> bool {
+		data.len() == Self::V0_LEN || data.get(8).is_some_and(|version| *version < Self::VERSION)
+	}
+}
+
+/// `Dog` layout before the version header, from before dogs belonged to shelters
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DogV0 {
 	pub name: String,
 	pub age: u8,
 	pub image_url: String,
@@ -16,3 +305,48 @@ pub struct Dog {
 	pub admin: Pubkey,
 	pub active: bool,
 }
+
+impl From<DogV0> for Dog {
+	fn from(v0: DogV0) -> Self {
+		// Carry the flags over as open needs without a target, so they survive
+		// `sync_need_flags` until the shelter closes them
+		let mut needs = Vec::new();
+		for (flagged, category, description) in [
+			(v0.needs_food, SpendingCategory::Food, "Food"),
+			(v0.needs_toys, SpendingCategory::Toys, "Toys"),
+			(v0.needs_medical, SpendingCategory::Medical, "Medical care"),
+			(v0.needs_shelter, SpendingCategory::Shelter, "Shelter"),
+		] {
+			if flagged {
+				needs.push(Need { category, description: description.to_string(), target: 0, raised: 0, fulfilled: false });
+			}
+		}
+		if !v0.needs_other.trim().is_empty() {
+			let mut description = v0.needs_other;
+			if description.len() > crate::MAX_NEED_DESCRIPTION_LEN {
+				let mut end = crate::MAX_NEED_DESCRIPTION_LEN;
+				while !description.is_char_boundary(end) {
+					end -= 1;
+				}
+				description.truncate(end);
+			}
+			needs.push(Need { category: SpendingCategory::Other, description, target: 0, raised: 0, fulfilled: false });
+		}
+
+		let mut dog = Dog {
+			version: Dog::VERSION,
+			name: v0.name,
+			age: v0.age,
+			image_url: v0.image_url,
+			story: v0.story,
+			total_donations: v0.total_donations,
+			admin: v0.admin,
+			active: v0.active,
+			needs,
+			// These dogs were created before shelters and are not linked to one
+			..Default::default()
+		};
+		dog.sync_need_flags();
+		dog
+	}
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::{MAX_DOG_MEDIA, MAX_MEDIA_CAPTION_LEN, MAX_MEDIA_URI_LEN};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MediaKind {
+	Image,
+	Video,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct MediaEntry {
+	pub kind: MediaKind,
+	/// `https://`, `ipfs://` or `ar://` location of the file
+	pub uri: String,
+	/// SHA-256 of the file, so clients can verify what the URI serves
+	pub content_hash: [u8; 32],
+	pub caption: String,
+}
+
+impl MediaEntry {
+	pub fn validate(&self) -> Result<()> {
+		if !is_supported_uri(&self.uri)
+			|| self.uri.len() > MAX_MEDIA_URI_LEN
+			|| self.caption.len() > MAX_MEDIA_CAPTION_LEN
+			|| self.content_hash == [0; 32]
+		{
+			return err!(crate::error::WoofiError::InvalidDogMedia);
+		}
+		Ok(())
+	}
+}
+
+/// Whether `uri` points to an `https://`, `ipfs://` or `ar://` location
+pub fn is_supported_uri(uri: &str) -> bool {
+	["https://", "ipfs://", "ar://"]
+		.iter()
+		.any(|scheme| uri.len() > scheme.len() && uri.starts_with(scheme))
+}
+
+/// Gallery of a dog, in display order
+#[account]
+pub struct DogMedia {
+	pub dog: Pubkey,
+	pub entries: Vec<MediaEntry>,
+}
+
+impl DogMedia {
+	pub fn add(&mut self, entry: MediaEntry) -> Result<()> {
+		entry.validate()?;
+		if self.entries.len() >= MAX_DOG_MEDIA {
+			return err!(crate::error::WoofiError::DogMediaFull);
+		}
+		self.entries.push(entry);
+		Ok(())
+	}
+
+	pub fn remove(&mut self, index: u8) -> Result<MediaEntry> {
+		if index as usize >= self.entries.len() {
+			return err!(crate::error::WoofiError::InvalidMediaIndex);
+		}
+		Ok(self.entries.remove(index as usize))
+	}
+
+	/// Reorder the gallery, `order` lists the current indexes in their new order
+	pub fn reorder(&mut self, order: &[u8]) -> Result<()> {
+		let mut seen = [false; MAX_DOG_MEDIA];
+		if order.len() != self.entries.len() {
+			return err!(crate::error::WoofiError::InvalidMediaIndex);
+		}
+		for &index in order {
+			match seen.get_mut(index as usize) {
+				Some(seen) if !*seen && (index as usize) < self.entries.len() => *seen = true,
+				_ => return err!(crate::error::WoofiError::InvalidMediaIndex),
+			}
+		}
+		self.entries = order.iter().map(|&index| self.entries[index as usize].clone()).collect();
+		Ok(())
+	}
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// A proposed move of a dog to another shelter, PDA `[b"dog_transfer", dog]`.
+/// Closed once the destination accepts or either side cancels.
+#[account]
+pub struct DogTransfer {
+	pub dog: Pubkey,
+	pub from_shelter: Pubkey,
+	pub to_shelter: Pubkey,
+	/// Source shelter admin who proposed the move, refunded the rent on close
+	pub proposed_by: Pubkey,
+	pub proposed_at: i64,
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// A progress post in a dog's news feed, PDA `[b"dog_update", dog, index]`
+#[account]
+pub struct DogUpdate {
+	pub dog: Pubkey,
+	/// Position of this post in the dog's feed
+	pub index: u64,
+	pub title: String,
+	/// Inline text, may be empty when only `body_hash` is given
+	pub body: String,
+	/// SHA-256 of an off-chain body, zero when the body is inline
+	pub body_hash: [u8; 32],
+	/// Optional photo or video, `https://`, `ipfs://` or `ar://`
+	pub media_uri: String,
+	pub author: Pubkey,
+	pub posted_at: i64,
+}
@@ -2,10 +2,99 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(Default)]
 pub struct Donation {
+	/// Layout version, see `Donation::VERSION`
+	pub version: u8,
 	pub donor: Pubkey,
 	pub dog_id: Pubkey,
 	pub amount: u64,
 	pub timestamp: i64,
 	pub message: String,
+	/// Clients and indexers must not display the donor or rank them on leaderboards
+	pub anonymous: bool,
+	/// Mint of the receipt NFT, or the default pubkey if none was minted
+	pub receipt_mint: Pubkey,
+	/// Set by a curator to hide the message from public pages
+	pub message_hidden: bool,
+	/// Unix timestamp from the cluster clock when the donation was recorded
+	pub created_at: i64,
+	/// Unix timestamp of the donor's last message edit, 0 if never edited
+	pub message_edited_at: i64,
+	/// Public reply from the shelter
+	pub thank_you: String,
+	/// Whether the dog's shelter was verified when the donation was made
+	pub shelter_verified: bool,
+	/// Index in the dog's `needs` the donation was earmarked for
+	pub need_index: Option<u8>,
+}
+
+impl Donation {
+	/// Current layout version. Layouts are append-only: new fields go at the end and
+	/// `migrate_donation` grows older accounts in place.
+	pub const VERSION: u8 = 1;
+
+	/// Size allocated for donation accounts, the `space` of `donate`
+	pub const LEN: usize = 550;
+
+	/// Size of donation accounts created before the version header, the original `space` of `donate`
+	pub const V0_LEN: usize = 292;
+
+	/// Prefix of the message a donor signs to prove they made a donation.
+	pub const RECEIPT_CHALLENGE_PREFIX: &'static [u8] = b"woofi-donation-receipt:";
+
+	/// Build the challenge a donor signs with the `donor` key to prove ownership of
+	/// the donation record at `donation`, e.g. when requesting a receipt for an
+	/// anonymous donation. `nonce` is chosen by the verifier to prevent replays.
+	pub fn receipt_challenge(donation: &Pubkey, nonce: &[u8]) -> Vec<u8> {
+		let mut challenge = Vec::with_capacity(
+			Self::RECEIPT_CHALLENGE_PREFIX.len() + 32 + nonce.len(),
+		);
+		challenge.extend_from_slice(Self::RECEIPT_CHALLENGE_PREFIX);
+		challenge.extend_from_slice(donation.as_ref());
+		challenge.extend_from_slice(nonce);
+		challenge
+	}
+
+	/// Decode an account of any layout version, upgrading older layouts in memory
+	pub fn try_deserialize_versioned(data: &[u8]) -> Result<Self> {
+		if !data.starts_with(Self::DISCRIMINATOR) {
+			return err!(ErrorCode::AccountDiscriminatorMismatch);
+		}
+		if data.len() == Self::V0_LEN {
+			return Ok(DonationV0::deserialize(&mut &data[8..])?.into());
+		}
+		Self::try_deserialize(&mut &data[..])
+	}
+
+	/// Whether `data` holds an older layout that must be migrated before use
+	pub fn is_outdated(data: &[u8]) -> bool {
+		data.len() == Self::V0_LEN || data.get(8).is_some_and(|version| *version < Self::VERSION)
+	}
+}
+
+/// `Donation` layout before the version header
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DonationV0 {
+	pub donor: Pubkey,
+	pub dog_id: Pubkey,
+	pub amount: u64,
+	pub timestamp: i64,
+	pub message: String,
+}
+
+impl From<DonationV0> for Donation {
+	fn from(v0: DonationV0) -> Self {
+		Donation {
+			version: Donation::VERSION,
+			donor: v0.donor,
+			dog_id: v0.dog_id,
+			amount: v0.amount,
+			timestamp: v0.timestamp,
+			message: v0.message,
+			// Not recorded, readers fall back to the donor-supplied `timestamp`
+			created_at: 0,
+			..Default::default()
+		}
+	}
 }
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// A foster volunteer who can take dogs home, PDA `[b"foster", wallet]`
+#[account]
+pub struct Foster {
+	pub wallet: Pubkey,
+	/// Where the foster lives, e.g. "Lisbon"
+	pub region: String,
+	/// Number of dogs the foster can care for at once
+	pub capacity: u8,
+	/// Number of dogs currently assigned to the foster
+	pub dog_count: u8,
+	/// Set by the platform admin once the volunteer has been vetted
+	pub verified: bool,
+	/// Unix timestamp of the verification, 0 if never verified
+	pub verified_at: i64,
+}
+
+impl Foster {
+	/// Take in one more dog, refusing unverified or full fosters
+	pub fn take_dog(&mut self) -> Result<()> {
+		if !self.verified {
+			return err!(crate::error::WoofiError::FosterNotVerified);
+		}
+		if self.dog_count >= self.capacity {
+			return err!(crate::error::WoofiError::FosterAtCapacity);
+		}
+		self.dog_count += 1;
+		Ok(())
+	}
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// One entry of a dog's treatment timeline, PDA `[b"medical_record", dog, index]`
+#[account]
+pub struct MedicalRecord {
+	pub dog: Pubkey,
+	/// Position of this record in the dog's timeline
+	pub index: u64,
+	/// Unix timestamp of the treatment
+	pub date: i64,
+	pub procedure: String,
+	/// Wallet of the registered payee that treated the dog
+	pub clinic: Pubkey,
+	/// Lamports the treatment cost
+	pub cost: u64,
+	/// The withdrawal that paid for the treatment, if any
+	pub withdrawal: Option<Pubkey>,
+	/// Content hash of the vet report or invoice
+	pub document_hash: [u8; 32],
+	/// Curator or admin who recorded the treatment
+	pub recorded_by: Pubkey,
+	pub recorded_at: i64,
+}
@@ -1,10 +1,34 @@
 
 use anchor_lang::prelude::*;
 
+pub mod adoption_application;
+pub mod curator;
 pub mod dog;
+pub mod dog_media;
+pub mod dog_transfer;
+pub mod dog_update;
+pub mod medical_record;
 pub mod donation;
+pub mod foster;
+pub mod moderation_config;
+pub mod payee;
 pub mod platform;
+pub mod shelter;
+pub mod treasury_history;
+pub mod withdrawal;
 
+pub use adoption_application::*;
+pub use curator::*;
 pub use dog::*;
+pub use dog_media::*;
+pub use dog_transfer::*;
+pub use dog_update::*;
+pub use medical_record::*;
 pub use donation::*;
+pub use foster::*;
+pub use moderation_config::*;
+pub use payee::*;
 pub use platform::*;
+pub use shelter::*;
+pub use treasury_history::*;
+pub use withdrawal::*;
@@ -0,0 +1,41 @@
+
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ModerationConfig {
+	/// Maximum number of links allowed in a donation message
+	pub max_urls: u8,
+	/// Case-insensitive substrings rejected in donation messages
+	pub banned_substrings: Vec<String>,
+}
+
+impl ModerationConfig {
+	/// Load the config from its PDA, which only exists once the admin has set it
+	pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+		if account.owner != &crate::ID || account.data_is_empty() {
+			return Ok(None);
+		}
+
+		let data = account.try_borrow_data()?;
+		Ok(Some(Self::try_deserialize(&mut data.as_ref())?))
+	}
+
+	/// Reject messages with banned content or too many links
+	pub fn check_message(&self, message: &str) -> Result<()> {
+		let message = message.to_lowercase();
+
+		if self.banned_substrings.iter().any(|banned| message.contains(&banned.to_lowercase())) {
+			return err!(crate::error::WoofiError::MessageNotAllowed);
+		}
+
+		let urls = message
+			.split_whitespace()
+			.filter(|word| word.contains("://") || word.starts_with("www."))
+			.count();
+		if urls > self.max_urls as usize {
+			return err!(crate::error::WoofiError::TooManyUrls);
+		}
+
+		Ok(())
+	}
+}
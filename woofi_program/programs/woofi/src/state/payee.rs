@@ -0,0 +1,10 @@
+
+use anchor_lang::prelude::*;
+
+/// A vetted recipient of withdrawals, e.g. a vet clinic or food supplier
+#[account]
+pub struct Payee {
+	pub wallet: Pubkey,
+	pub label: String,
+	pub added_by: Pubkey,
+}
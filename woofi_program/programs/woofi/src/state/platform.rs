@@ -2,10 +2,129 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(InitSpace)]
 pub struct Platform {
+	/// Layout version, see `Platform::VERSION`
+	pub version: u8,
+	pub admin: Pubkey,
+	/// Platform wallet set at initialization. Donations and withdrawals use each
+	/// shelter's treasury, which `update_treasury` changes.
+	pub treasury: Pubkey,
+	pub total_donations: u64,
+	pub dog_count: u64,
+	pub donation_count: u64,
+	pub receipts_enabled: bool,
+	pub receipt_min_amount: u64,
+	pub shelter_count: u64,
+	/// Reject donations to shelters that have not been verified
+	pub require_verified_shelters: bool,
+	/// Bitmask of `PAUSE_*` flags halting groups of instructions
+	pub paused: u8,
+	/// Key allowed to pause the program besides the admin, the default pubkey if unset
+	pub guardian: Pubkey,
+	/// Delay between proposing and applying a shelter treasury change, 0 to apply immediately
+	pub treasury_timelock_secs: i64,
+	/// Only allow withdrawals to wallets with a `Payee` record
+	pub require_registered_payees: bool,
+	/// Lamports each shelter may withdraw per period, 0 for no limit
+	pub max_withdraw_per_period: u64,
+	/// Length of the withdrawal period in seconds
+	pub withdraw_period_secs: i64,
+	/// Lamports withdrawn from all shelter treasuries
+	pub total_withdrawn: u64,
+	/// Lamports taken as platform fees, 0 as long as the platform charges no fees
+	pub total_fees: u64,
+}
+
+impl Platform {
+	/// Current layout version. Layouts are append-only: new fields go at the end and
+	/// `migrate_platform` grows older accounts in place.
+	pub const VERSION: u8 = 1;
+
+	/// Size of platform accounts created before the version header, allocated as
+	/// `8 + size_of::<PlatformV0>()`
+	pub const V0_LEN: usize = 88;
+
+	/// Decode an account of any layout version, upgrading older layouts in memory
+	pub fn try_deserialize_versioned(data: &[u8]) -> Result<Self> {
+		if data.len() == Self::V0_LEN {
+			if !data.starts_with(Self::DISCRIMINATOR) {
+				return err!(ErrorCode::AccountDiscriminatorMismatch);
+			}
+			return Ok(PlatformV0::deserialize(&mut &data[8..])?.into());
+		}
+		Self::try_deserialize(&mut &data[..])
+	}
+
+	/// Whether `data` holds an older layout that must be migrated before use
+	pub fn is_outdated(data: &[u8]) -> bool {
+		data.len() == Self::V0_LEN
+			|| data.get(8).is_some_and(|version| *version < Self::VERSION)
+	}
+
+	/// Book a donation of `amount` lamports, leaving the stats untouched on overflow
+	pub fn record_donation(&mut self, amount: u64) -> Result<()> {
+		let total_donations = self.total_donations
+			.checked_add(amount)
+			.ok_or(crate::error::WoofiError::MathOverflow)?;
+		let donation_count = self.donation_count
+			.checked_add(1)
+			.ok_or(crate::error::WoofiError::MathOverflow)?;
+
+		self.total_donations = total_donations;
+		self.donation_count = donation_count;
+		Ok(())
+	}
+
+	/// Count a newly added dog
+	pub fn record_dog(&mut self) -> Result<()> {
+		self.dog_count = self.dog_count
+			.checked_add(1)
+			.ok_or(crate::error::WoofiError::MathOverflow)?;
+		Ok(())
+	}
+
+	/// Fail with `Paused` if any of the `flags` is set
+	pub fn check_not_paused(&self, flags: u8) -> Result<()> {
+		if self.paused & flags != 0 {
+			return err!(crate::error::WoofiError::Paused);
+		}
+		Ok(())
+	}
+}
+
+/// `Platform` layout before the version header, read by `migrate_platform`
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PlatformV0 {
 	pub admin: Pubkey,
 	pub treasury: Pubkey,
 	pub total_donations: u64,
 	pub dog_count: u32,
 	pub donation_count: u32,
 }
+
+impl From<PlatformV0> for Platform {
+	fn from(v0: PlatformV0) -> Self {
+		Platform {
+			version: Platform::VERSION,
+			admin: v0.admin,
+			treasury: v0.treasury,
+			total_donations: v0.total_donations,
+			dog_count: v0.dog_count.into(),
+			donation_count: v0.donation_count.into(),
+			receipts_enabled: false,
+			receipt_min_amount: 0,
+			shelter_count: 0,
+			require_verified_shelters: false,
+			paused: 0,
+			guardian: Pubkey::default(),
+			treasury_timelock_secs: 0,
+			require_registered_payees: false,
+			max_withdraw_per_period: 0,
+			withdraw_period_secs: 0,
+			// Withdrawals were not booked before the version header
+			total_withdrawn: 0,
+			total_fees: 0,
+		}
+	}
+}
@@ -0,0 +1,91 @@
+
+use anchor_lang::prelude::*;
+
+use crate::MAX_SHELTER_NAME_LEN;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Shelter {
+	#[max_len(MAX_SHELTER_NAME_LEN)]
+	pub name: String,
+	pub admin: Pubkey,
+	pub treasury: Pubkey,
+	pub total_donations: u64,
+	pub dog_count: u64,
+	pub donation_count: u64,
+	/// Set by the platform admin once the organization has been vetted
+	pub verified: bool,
+	/// Hash of the off-chain verification documents, zeroed when unverified
+	pub verification_hash: [u8; 32],
+	/// Unix timestamp of the last verification, 0 if never verified
+	pub verified_at: i64,
+	/// Number of withdrawals, also the index of the next `Withdrawal` record
+	pub withdrawal_count: u64,
+	/// Unix timestamp when the current withdrawal period started
+	pub period_start: i64,
+	/// Lamports withdrawn in the current period
+	pub period_withdrawn: u64,
+	/// Lamports withdrawn from the shelter treasury, not counting transfers
+	pub total_withdrawn: u64,
+	/// Lamports the current treasury held when it became the shelter's treasury
+	pub opening_balance: u64,
+	/// `booked_balance` when the current treasury became the shelter's treasury, as
+	/// funds booked before then stay in the earlier treasuries
+	pub opening_booked: u64,
+	/// Lamports that arrived with dogs transferred from other shelters
+	pub transferred_in: u64,
+	/// Lamports that left with dogs transferred to other shelters
+	pub transferred_out: u64,
+}
+
+impl Shelter {
+	/// Lamports the treasury should hold according to the recorded donations,
+	/// withdrawals and transfers
+	pub fn booked_balance(&self) -> u64 {
+		self.total_donations
+			.saturating_add(self.transferred_in)
+			.saturating_sub(self.total_withdrawn.saturating_add(self.transferred_out))
+	}
+
+	/// Lamports the current treasury should hold: its opening balance plus the
+	/// donations and transfers booked since, minus the withdrawals
+	pub fn expected_treasury_balance(&self) -> Result<u64> {
+		let expected = self.opening_balance as i128 + self.booked_balance() as i128 - self.opening_booked as i128;
+		u64::try_from(expected).map_err(|_| error!(crate::error::WoofiError::MathOverflow))
+	}
+
+	/// Switch to `treasury`, which holds `lamports`, and reconcile it from here on
+	pub fn open_treasury(&mut self, treasury: Pubkey, lamports: u64) {
+		self.treasury = treasury;
+		self.opening_balance = lamports;
+		self.opening_booked = self.booked_balance();
+	}
+
+	/// Lamports the shelter may still withdraw at `now` under the platform's period cap
+	pub fn remaining_allowance(&self, platform: &crate::Platform, now: i64) -> u64 {
+		if platform.max_withdraw_per_period == 0 {
+			return u64::MAX;
+		}
+		if self.period_expired(platform, now) {
+			return platform.max_withdraw_per_period;
+		}
+		platform.max_withdraw_per_period.saturating_sub(self.period_withdrawn)
+	}
+
+	/// Count `amount` against the period cap, starting a new period if the current one expired
+	pub fn record_withdrawal(&mut self, platform: &crate::Platform, now: i64, amount: u64) -> Result<()> {
+		if amount > self.remaining_allowance(platform, now) {
+			return err!(crate::error::WoofiError::WithdrawalLimitExceeded);
+		}
+		if self.period_expired(platform, now) {
+			self.period_start = now;
+			self.period_withdrawn = 0;
+		}
+		self.period_withdrawn = self.period_withdrawn.saturating_add(amount);
+		Ok(())
+	}
+
+	fn period_expired(&self, platform: &crate::Platform, now: i64) -> bool {
+		now >= self.period_start.saturating_add(platform.withdraw_period_secs)
+	}
+}
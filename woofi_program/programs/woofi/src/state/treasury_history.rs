@@ -0,0 +1,44 @@
+
+use anchor_lang::prelude::*;
+
+use crate::TREASURY_HISTORY_LEN;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct TreasuryChange {
+	/// The treasury that was replaced
+	pub treasury: Pubkey,
+	/// Unix timestamp when it was replaced
+	pub replaced_at: i64,
+}
+
+/// Treasury changes of one shelter: the change waiting for the platform timelock and a
+/// ring buffer of previous treasuries, oldest entries are overwritten
+#[account]
+pub struct TreasuryHistory {
+	/// Treasury waiting for the timelock, the default pubkey if none is pending
+	pub pending_treasury: Pubkey,
+	/// Unix timestamp after which `pending_treasury` can be applied
+	pub pending_treasury_eta: i64,
+	/// Index the next change is written to
+	pub head: u8,
+	/// Number of valid entries, at most `TREASURY_HISTORY_LEN`
+	pub len: u8,
+	pub entries: [TreasuryChange; TREASURY_HISTORY_LEN],
+}
+
+impl TreasuryHistory {
+	pub fn push(&mut self, treasury: Pubkey, replaced_at: i64) {
+		self.entries[self.head as usize] = TreasuryChange { treasury, replaced_at };
+		self.head = ((self.head as usize + 1) % TREASURY_HISTORY_LEN) as u8;
+		if (self.len as usize) < TREASURY_HISTORY_LEN {
+			self.len += 1;
+		}
+	}
+
+	/// Previous treasuries from the most recent to the oldest
+	pub fn changes(&self) -> impl Iterator<Item = &TreasuryChange> {
+		(1..=self.len as usize).map(move |back| {
+			&self.entries[(self.head as usize + TREASURY_HISTORY_LEN - back) % TREASURY_HISTORY_LEN]
+		})
+	}
+}
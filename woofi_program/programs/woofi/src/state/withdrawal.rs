@@ -0,0 +1,32 @@
+
+use anchor_lang::prelude::*;
+
+/// What withdrawn funds were spent on, matching the dog's `needs_*` flags
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum SpendingCategory {
+	Food,
+	Medical,
+	Shelter,
+	Toys,
+	Other,
+	/// A dog's unspent balance moved to another shelter's treasury with the dog,
+	/// only recorded by `accept_dog_transfer`
+	Transfer,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Withdrawal {
+	pub shelter: Pubkey,
+	/// The dog the funds were spent on, if any
+	pub dog: Option<Pubkey>,
+	pub amount: u64,
+	pub recipient: Pubkey,
+	pub timestamp: i64,
+	pub category: SpendingCategory,
+	/// Content hash of the invoice, so the off-chain document can be verified;
+	/// zero for transfers
+	pub invoice_hash: [u8; 32],
+	/// Position of this withdrawal in the shelter's history
+	pub index: u64,
+}
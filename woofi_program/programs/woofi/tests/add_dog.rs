@@ -7,6 +7,7 @@ use {
 		woofi_ix_interface,
 	},
     solana_program_test::tokio,
+    woofi::state::DogProfile,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
     },
@@ -34,20 +35,21 @@ async fn add_dog_ix_success() {
 
 	// DATA
 	let name: String = Default::default();
-	let age: u8 = Default::default();
+	let intake_date: i64 = Default::default();
+	let birth_date: i64 = Default::default();
 	let image_url: String = Default::default();
 	let story: String = Default::default();
-	let needs_food: bool = Default::default();
-	let needs_toys: bool = Default::default();
-	let needs_medical: bool = Default::default();
-	let needs_shelter: bool = Default::default();
-	let needs_other: String = Default::default();
+	let profile: DogProfile = Default::default();
+	let metadata_uri: String = Default::default();
+	let metadata_hash: [u8; 32] = Default::default();
 
 	// KEYPAIR
 	let admin_keypair = Keypair::new();
+	let shelter_keypair = Keypair::new();
 
 	// PUBKEY
 	let admin_pubkey = admin_keypair.pubkey();
+	let shelter_pubkey = shelter_keypair.pubkey();
 
 	// EXECUTABLE PUBKEY
 	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
@@ -63,6 +65,7 @@ async fn add_dog_ix_success() {
 	let (dog_pda, _dog_pda_bump) = Pubkey::find_program_address(
 		&[
 			b"dog",
+			shelter_pubkey.as_ref(),
 			name.as_bytes().as_ref(),
 		],
 		&woofi::ID,
@@ -86,17 +89,17 @@ async fn add_dog_ix_success() {
 	let ix = woofi_ix_interface::add_dog_ix_setup(
 		&admin_keypair,
 		platform_pda,
+		shelter_pubkey,
 		dog_pda,
 		system_program_pubkey,
 		&name,
-		age,
+		intake_date,
+		birth_date,
 		&image_url,
 		&story,
-		needs_food,
-		needs_toys,
-		needs_medical,
-		needs_shelter,
-		&needs_other,
+		profile,
+		&metadata_uri,
+		metadata_hash,
 		recent_blockhash,
 	);
 
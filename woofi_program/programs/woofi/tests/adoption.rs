@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+use woofi::state::{AdoptionApplication, ApplicationStatus, Dog, DogLifecycle};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_adoption_workflow() {
+    let (mut env, [applicant, other_applicant, curator, donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    add_curator(banks_client, payer, recent_blockhash, admin, &curator.pubkey())
+        .await
+        .expect("Failed to add curator");
+    let application = submit_adoption_application(banks_client, payer, recent_blockhash, &applicant, dog, [5; 32])
+        .await
+        .expect("Failed to apply");
+    let other_application = submit_adoption_application(banks_client, payer, recent_blockhash, &other_applicant, dog, [6; 32])
+        .await
+        .expect("Failed to apply");
+
+    let application_account: AdoptionApplication = fetch_account(banks_client, &application).await;
+    assert_eq!(application_account.status, ApplicationStatus::Submitted, "Status mismatch");
+    assert_eq!(application_account.applicant, applicant.pubkey(), "Applicant mismatch");
+
+    // Should fail because the application has not been reviewed yet
+    let result = review_adoption_application(
+        banks_client, payer, recent_blockhash, &curator, true, dog, &application, ApplicationStatus::Approved,
+    )
+    .await;
+    assert!(result.is_err(), "Applications must be reviewed before approval");
+
+    for status in [ApplicationStatus::UnderReview, ApplicationStatus::Approved] {
+        review_adoption_application(banks_client, payer, recent_blockhash, &curator, true, dog, &application, status)
+            .await
+            .expect("Failed to review application");
+    }
+
+    let application_account: AdoptionApplication = fetch_account(banks_client, &application).await;
+    assert_eq!(application_account.status, ApplicationStatus::Approved, "Status mismatch");
+    assert_eq!(application_account.reviewed_by, curator.pubkey(), "Reviewer mismatch");
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.lifecycle, DogLifecycle::Adopted, "Dog should be adopted");
+    assert!(!dog_account.active, "Adopted dogs are no longer listed");
+
+    // Should fail because the dog has been adopted
+    let result = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "Good boy", 1,
+    )
+    .await;
+    assert!(result.is_err(), "Adopted dogs cannot receive donations");
+    let result = review_adoption_application(
+        banks_client, payer, recent_blockhash, admin, false, dog, &other_application, ApplicationStatus::UnderReview,
+    )
+    .await;
+    assert!(result.is_err(), "Other applications cannot progress once the dog is adopted");
+
+    // Remaining applications can still be turned down
+    review_adoption_application(
+        banks_client, payer, recent_blockhash, admin, false, dog, &other_application, ApplicationStatus::Rejected,
+    )
+    .await
+    .expect("Failed to reject application");
+
+    let application_account: AdoptionApplication = fetch_account(banks_client, &other_application).await;
+    assert_eq!(application_account.status, ApplicationStatus::Rejected, "Status mismatch");
+}
+
+#[tokio::test]
+async fn test_reject_application() {
+    let (mut env, [applicant]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    // Should fail because the contact hash is missing
+    let result = submit_adoption_application(banks_client, payer, recent_blockhash, &applicant, dog, [0; 32]).await;
+    assert!(result.is_err(), "Applications need a contact hash");
+
+    let application = submit_adoption_application(banks_client, payer, recent_blockhash, &applicant, dog, [5; 32])
+        .await
+        .expect("Failed to apply");
+
+    // Should fail because the applicant is not a curator
+    let result = review_adoption_application(
+        banks_client, payer, recent_blockhash, &applicant, false, dog, &application, ApplicationStatus::UnderReview,
+    )
+    .await;
+    assert!(result.is_err(), "Applicants cannot review their own applications");
+
+    for status in [ApplicationStatus::UnderReview, ApplicationStatus::Rejected] {
+        review_adoption_application(banks_client, payer, recent_blockhash, admin, false, dog, &application, status)
+            .await
+            .expect("Failed to review application");
+    }
+
+    // Should fail because rejected applications are final
+    let result = review_adoption_application(
+        banks_client, payer, recent_blockhash, admin, false, dog, &application, ApplicationStatus::UnderReview,
+    )
+    .await;
+    assert!(result.is_err(), "Rejected applications cannot be reopened");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.lifecycle, DogLifecycle::Available, "Dog should still be available");
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use woofi::state::{Dog, Donation, Shelter};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_anonymous_donation() {
+    let (mut env, [donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, treasury, shelter, dog, .. } = &mut env;
+
+    let donation = donate_anonymously(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, 1,
+    )
+    .await
+    .expect("Failed to donate anonymously");
+
+    let donation_account: Donation = fetch_account(banks_client, &donation).await;
+    assert!(donation_account.anonymous, "Donation should be anonymous");
+    assert_eq!(donation_account.amount, 1_000_000, "Amount mismatch");
+    assert!(donation_account.message.is_empty(), "Message should be empty");
+
+    // Totals are still counted, without anything keyed by the donor
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.total_donations, 1_000_000, "Dog total mismatch");
+    let shelter_account: Shelter = fetch_account(banks_client, shelter).await;
+    assert_eq!(shelter_account.donation_count, 1, "Shelter donation count mismatch");
+
+    // The donor key stays on the record so the donor alone can prove the gift
+    let challenge = Donation::receipt_challenge(&donation, b"nonce");
+    let signature = donor.sign_message(&challenge);
+    assert!(
+        signature.verify(donation_account.donor.as_ref(), &challenge),
+        "Donor should be able to prove the anonymous donation"
+    );
+    let stranger = Keypair::new();
+    assert!(
+        !stranger.sign_message(&challenge).verify(donation_account.donor.as_ref(), &challenge),
+        "Other wallets should not be able to prove the anonymous donation"
+    );
+}
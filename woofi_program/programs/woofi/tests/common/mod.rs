@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use solana_program_test::*;
 use solana_sdk::{
     account::Account,
+    instruction::Instruction,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
     system_program,
@@ -51,9 +52,14 @@ pub fn find_platform_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"platform"], &program_id())
 }
 
+// Helper function to find a shelter PDA
+pub fn find_shelter_pda(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"shelter", name.as_bytes()], &program_id())
+}
+
 // Helper function to find a dog PDA
-pub fn find_dog_pda(name: &str) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[b"dog", name.as_bytes()], &program_id())
+pub fn find_dog_pda(shelter: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"dog", shelter.as_ref(), name.as_bytes()], &program_id())
 }
 
 // Helper function to find a donation PDA
@@ -91,14 +97,7 @@ pub async fn create_platform(
         .to_vec(),
     };
 
-    let transaction = Transaction::new_signed_with_payer(
-        &[initialize_ix],
-        Some(&payer.pubkey()),
-        &[payer, admin],
-        *recent_blockhash,
-    );
-
-    banks_client.process_transaction(transaction).await
+    process_instruction(banks_client, payer, recent_blockhash, initialize_ix, &[admin]).await
 }
 
 // Helper function to add a dog
@@ -107,24 +106,25 @@ pub async fn add_dog(
     payer: &Keypair,
     recent_blockhash: &solana_sdk::hash::Hash,
     admin: &Keypair,
+    shelter: &Pubkey,
     name: &str,
-    age: u8,
+    intake_date: i64,
+    birth_date: i64,
     image_url: &str,
     story: &str,
-    needs_food: bool,
-    needs_toys: bool,
-    needs_medical: bool,
-    needs_shelter: bool,
-    needs_other: &str,
+    profile: DogProfile,
+    metadata_uri: &str,
+    metadata_hash: [u8; 32],
 ) -> Result<(), BanksClientError> {
     let (platform_pda, _) = find_platform_pda();
-    let (dog_pda, _) = find_dog_pda(name);
+    let (dog_pda, _) = find_dog_pda(shelter, name);
 
     let add_dog_ix = Instruction {
         program_id: program_id(),
         accounts: vec![
             AccountMeta::new(admin.pubkey(), true),
             AccountMeta::new(platform_pda, false),
+            AccountMeta::new(*shelter, false),
             AccountMeta::new(dog_pda, false),
             AccountMeta::new_readonly(system_program::id(), false),
         ],
@@ -132,25 +132,1486 @@ pub async fn add_dog(
             anchor_lang::Discriminator::new("add_dog"),
             woofi::instruction::AddDog {
                 name: name.to_string(),
-                age,
+                intake_date,
+                birth_date,
                 image_url: image_url.to_string(),
                 story: story.to_string(),
-                needs_food,
-                needs_toys,
-                needs_medical,
-                needs_shelter,
-                needs_other: needs_other.to_string(),
+                profile,
+                metadata_uri: metadata_uri.to_string(),
+                metadata_hash,
             },
         )
         .to_vec(),
     };
 
-    let transaction = Transaction::new_signed_with_payer(
-        &[add_dog_ix],
+    process_instruction(banks_client, payer, recent_blockhash, add_dog_ix, &[admin]).await
+}
+
+// Helper function to find the moderation config PDA
+pub fn find_moderation_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"moderation"], &program_id())
+}
+
+// Helper function to find a curator PDA
+pub fn find_curator_pda(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"curator", wallet.as_ref()], &program_id())
+}
+
+// Helper function to find the receipt mint PDA of a donation
+pub fn find_receipt_mint_pda(donation: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"receipt_mint", donation.as_ref()], &program_id())
+}
+
+// Helper function to find the receipt token account PDA of a donation
+pub fn find_receipt_token_pda(donation: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"receipt_token", donation.as_ref()], &program_id())
+}
+
+// Helper function to find the Metaplex metadata PDA of a mint
+pub fn find_metadata_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"metadata", mpl_token_metadata::ID.as_ref(), mint.as_ref()],
+        &mpl_token_metadata::ID,
+    )
+}
+
+// Helper function to send a single instruction signed by the payer and the given signers.
+// BanksClient answers a transaction it has already processed from its status cache
+// without executing it again, so repeated transactions are re-signed with a newer blockhash
+pub async fn process_instruction(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    instruction: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+
+    let mut blockhash = *recent_blockhash;
+    let mut transaction = Transaction::new_signed_with_payer(
+        &[instruction.clone()],
         Some(&payer.pubkey()),
-        &[payer, admin],
-        *recent_blockhash,
+        &all_signers,
+        blockhash,
     );
+    while banks_client.get_transaction_status(transaction.signatures[0]).await?.is_some() {
+        blockhash = banks_client.get_new_latest_blockhash(&blockhash).await?;
+        transaction = Transaction::new_signed_with_payer(
+            &[instruction.clone()],
+            Some(&payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+    }
 
     banks_client.process_transaction(transaction).await
-}
\ No newline at end of file
+}
+
+// Helper function to make a donation to a dog
+pub async fn donate(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    donor: &Keypair,
+    shelter: &Pubkey,
+    dog: &Pubkey,
+    treasury: &Pubkey,
+    amount: u64,
+    message: &str,
+    timestamp: i64,
+) -> Result<Pubkey, BanksClientError> {
+    donate_to_need(banks_client, payer, recent_blockhash, donor, shelter, dog, treasury, amount, message, timestamp, None).await
+}
+
+// Helper function to make a donation earmarked for one of the dog's needs
+pub async fn donate_to_need(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    donor: &Keypair,
+    shelter: &Pubkey,
+    dog: &Pubkey,
+    treasury: &Pubkey,
+    amount: u64,
+    message: &str,
+    timestamp: i64,
+    need_index: Option<u8>,
+) -> Result<Pubkey, BanksClientError> {
+    send_donation(banks_client, payer, recent_blockhash, donor, shelter, dog, treasury, amount, message, timestamp, false, need_index).await
+}
+
+// Helper function to make an anonymous donation
+pub async fn donate_anonymously(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    donor: &Keypair,
+    shelter: &Pubkey,
+    dog: &Pubkey,
+    treasury: &Pubkey,
+    amount: u64,
+    timestamp: i64,
+) -> Result<Pubkey, BanksClientError> {
+    send_donation(banks_client, payer, recent_blockhash, donor, shelter, dog, treasury, amount, "", timestamp, true, None).await
+}
+
+// Helper function to send a donate instruction with every option
+pub async fn send_donation(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    donor: &Keypair,
+    shelter: &Pubkey,
+    dog: &Pubkey,
+    treasury: &Pubkey,
+    amount: u64,
+    message: &str,
+    timestamp: i64,
+    anonymous: bool,
+    need_index: Option<u8>,
+) -> Result<Pubkey, BanksClientError> {
+    let (platform_pda, _) = find_platform_pda();
+    let (donation_pda, _) = find_donation_pda(&donor.pubkey(), timestamp);
+
+    let donate_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::Donate {
+            donor: donor.pubkey(),
+            platform: platform_pda,
+            shelter: *shelter,
+            dog: *dog,
+            donation: donation_pda,
+            treasury: *treasury,
+            moderation: find_moderation_pda().0,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::Donate {
+            amount,
+            message: message.to_string(),
+            timestamp,
+            anonymous,
+            need_index,
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, donate_ix, &[donor]).await?;
+
+    Ok(donation_pda)
+}
+
+// Helper function to configure receipt NFT minting
+pub async fn set_receipt_config(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    enabled: bool,
+    min_amount: u64,
+) -> Result<(), BanksClientError> {
+    let (platform_pda, _) = find_platform_pda();
+
+    let set_receipt_config_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::SetReceiptConfig {
+            admin: admin.pubkey(),
+            platform: platform_pda,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::SetReceiptConfig {
+            enabled,
+            min_amount,
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, set_receipt_config_ix, &[admin]).await
+}
+
+// Helper function to mint a receipt NFT for a donation
+pub async fn mint_receipt(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    donor: &Keypair,
+    dog: &Pubkey,
+    donation: &Pubkey,
+    uri: &str,
+) -> Result<(), BanksClientError> {
+    let (platform_pda, _) = find_platform_pda();
+    let (mint_pda, _) = find_receipt_mint_pda(donation);
+    let (receipt_token_pda, _) = find_receipt_token_pda(donation);
+    let (metadata_pda, _) = find_metadata_pda(&mint_pda);
+
+    let mint_receipt_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::MintReceipt {
+            donor: donor.pubkey(),
+            platform: platform_pda,
+            dog: *dog,
+            donation: *donation,
+            mint: mint_pda,
+            receipt_token_account: receipt_token_pda,
+            metadata: metadata_pda,
+            token_program: anchor_spl::token::ID,
+            token_metadata_program: mpl_token_metadata::ID,
+            system_program: system_program::id(),
+            rent: solana_sdk::sysvar::rent::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::MintReceipt {
+            uri: uri.to_string(),
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, mint_receipt_ix, &[donor]).await
+}
+
+// Helper function to grant curator rights to a wallet
+pub async fn add_curator(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    wallet: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let add_curator_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::AddCurator {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            curator: find_curator_pda(wallet).0,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::AddCurator { wallet: *wallet }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, add_curator_ix, &[admin]).await
+}
+
+// Helper function to revoke curator rights from a wallet
+pub async fn remove_curator(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    wallet: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let remove_curator_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::RemoveCurator {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            curator: find_curator_pda(wallet).0,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::RemoveCurator {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, remove_curator_ix, &[admin]).await
+}
+
+// Helper function to set the moderation rules for donation messages
+pub async fn set_moderation_config(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    max_urls: u8,
+    banned_substrings: &[&str],
+) -> Result<(), BanksClientError> {
+    let set_moderation_config_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::SetModerationConfig {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            moderation: find_moderation_pda().0,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::SetModerationConfig {
+            max_urls,
+            banned_substrings: banned_substrings.iter().map(|s| s.to_string()).collect(),
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, set_moderation_config_ix, &[admin]).await
+}
+
+// Helper function to hide a donation message, as the admin or a curator
+pub async fn hide_donation_message(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    moderator: &Keypair,
+    is_curator: bool,
+    donation: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let hide_donation_message_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::HideDonationMessage {
+            moderator: moderator.pubkey(),
+            platform: find_platform_pda().0,
+            curator: is_curator.then(|| find_curator_pda(&moderator.pubkey()).0),
+            donation: *donation,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::HideDonationMessage {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, hide_donation_message_ix, &[moderator]).await
+}
+
+// Test environment with an initialized platform and one dog
+pub struct TestEnv {
+    pub banks_client: BanksClient,
+    pub payer: Keypair,
+    pub recent_blockhash: solana_sdk::hash::Hash,
+    pub admin: Keypair,
+    pub treasury: Keypair,
+    pub shelter: Pubkey,
+    pub dog: Pubkey,
+}
+
+// Helper function to start the program with a platform, one shelter and a dog named
+// `dog_name`; the platform admin also administers the shelter. The shelter treasury is
+// owned by the program so withdrawals can debit it
+pub async fn start_with_dog(mut program_test: ProgramTest, dog_name: &str) -> TestEnv {
+    let treasury = Keypair::new();
+    program_test.add_account(
+        treasury.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: program_id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let admin = Keypair::new();
+    airdrop(&mut banks_client, &payer, &recent_blockhash, &admin.pubkey(), 10_000_000_000).await;
+
+    create_platform(&mut banks_client, &payer, &recent_blockhash, &admin, &Keypair::new().pubkey())
+        .await
+        .expect("Failed to initialize platform");
+    let shelter = register_shelter(&mut banks_client, &payer, &recent_blockhash, &admin, "Happy Paws", &treasury.pubkey(), &admin.pubkey())
+        .await
+        .expect("Failed to register shelter");
+    add_dog(
+        &mut banks_client, &payer, &recent_blockhash, &admin, &shelter,
+        dog_name, 0, 0, "https://example.com/dog.png", "Found on the street", DogProfile::default(), "", [0; 32],
+    )
+    .await
+    .expect("Failed to add dog");
+
+    TestEnv {
+        banks_client,
+        payer,
+        recent_blockhash,
+        admin,
+        treasury,
+        shelter,
+        dog: find_dog_pda(&shelter, dog_name).0,
+    }
+}
+
+// Helper function to create a funded wallet
+pub async fn funded_keypair(env: &mut TestEnv) -> Keypair {
+    let keypair = Keypair::new();
+    airdrop(&mut env.banks_client, &env.payer, &env.recent_blockhash, &keypair.pubkey(), 10_000_000_000).await;
+    keypair
+}
+
+// Helper function to start the program like `start_with_dog` and fund `N` extra wallets
+pub async fn start_with_wallets<const N: usize>(program_test: ProgramTest, dog_name: &str) -> (TestEnv, [Keypair; N]) {
+    let mut env = start_with_dog(program_test, dog_name).await;
+    let mut wallets = Vec::with_capacity(N);
+    for _ in 0..N {
+        wallets.push(funded_keypair(&mut env).await);
+    }
+    let wallets = wallets.try_into().unwrap_or_else(|_| unreachable!("Exactly N wallets were funded"));
+    (env, wallets)
+}
+
+// Helper function to fetch and deserialize a program account
+pub async fn fetch_account<T: AccountDeserialize>(banks_client: &mut BanksClient, address: &Pubkey) -> T {
+    let account = banks_client
+        .get_account(*address)
+        .await
+        .expect("Failed to get account")
+        .expect("Account not found");
+    T::try_deserialize(&mut account.data.as_ref()).expect("Failed to deserialize account")
+}
+
+// Helper function to edit a donation message as the donor
+pub async fn edit_donation_message(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    donor: &Keypair,
+    donation: &Pubkey,
+    message: &str,
+) -> Result<(), BanksClientError> {
+    let edit_donation_message_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::EditDonationMessage {
+            donor: donor.pubkey(),
+            platform: find_platform_pda().0,
+            donation: *donation,
+            moderation: find_moderation_pda().0,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::EditDonationMessage {
+            message: message.to_string(),
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, edit_donation_message_ix, &[donor]).await
+}
+
+// Helper function to reply to a donation, as the admin or a curator
+pub async fn reply_to_donation(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    curator_signer: &Keypair,
+    is_curator: bool,
+    donation: &Pubkey,
+    thank_you: &str,
+) -> Result<(), BanksClientError> {
+    let reply_to_donation_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::ReplyToDonation {
+            curator_signer: curator_signer.pubkey(),
+            platform: find_platform_pda().0,
+            curator: is_curator.then(|| find_curator_pda(&curator_signer.pubkey()).0),
+            donation: *donation,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::ReplyToDonation {
+            thank_you: thank_you.to_string(),
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, reply_to_donation_ix, &[curator_signer]).await
+}
+
+// Helper function to register a shelter administered by `shelter_admin`, returning its address
+pub async fn register_shelter(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    name: &str,
+    treasury: &Pubkey,
+    shelter_admin: &Pubkey,
+) -> Result<Pubkey, BanksClientError> {
+    let (shelter_pda, _) = find_shelter_pda(name);
+
+    let register_shelter_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::RegisterShelter {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            shelter: shelter_pda,
+            shelter_treasury: *treasury,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::RegisterShelter {
+            name: name.to_string(),
+            treasury: *treasury,
+            shelter_admin: *shelter_admin,
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, register_shelter_ix, &[admin]).await?;
+
+    Ok(shelter_pda)
+}
+
+// Helper function to mark a shelter as verified
+pub async fn verify_shelter(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    shelter: &Pubkey,
+    verification_hash: [u8; 32],
+) -> Result<(), BanksClientError> {
+    let verify_shelter_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::VerifyShelter {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            shelter: *shelter,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::VerifyShelter { verification_hash }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, verify_shelter_ix, &[admin]).await
+}
+
+// Helper function to revoke a shelter's verification
+pub async fn revoke_shelter(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    shelter: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let revoke_shelter_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::RevokeShelter {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            shelter: *shelter,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::RevokeShelter {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, revoke_shelter_ix, &[admin]).await
+}
+
+// Helper function to set whether donations require a verified shelter
+pub async fn set_shelter_policy(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    require_verified_shelters: bool,
+) -> Result<(), BanksClientError> {
+    let set_shelter_policy_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::SetShelterPolicy {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::SetShelterPolicy { require_verified_shelters }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, set_shelter_policy_ix, &[admin]).await
+}
+
+// Helper function to set the pause flags, as the admin or the guardian
+pub async fn set_pause(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    authority: &Keypair,
+    paused: u8,
+) -> Result<(), BanksClientError> {
+    let set_pause_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::SetPause {
+            authority: authority.pubkey(),
+            platform: find_platform_pda().0,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::SetPause { paused }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, set_pause_ix, &[authority]).await
+}
+
+// Helper function to set the guardian key
+pub async fn set_guardian(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    guardian: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let set_guardian_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::SetGuardian {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::SetGuardian { guardian: *guardian }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, set_guardian_ix, &[admin]).await
+}
+
+// Helper function to find the treasury history PDA of a shelter
+pub fn find_treasury_history_pda(shelter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"treasury_history", shelter.as_ref()], &program_id())
+}
+
+// Helper function to propose or apply a shelter treasury change
+pub async fn update_treasury(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    shelter: &Pubkey,
+    treasury: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let update_treasury_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::UpdateTreasury {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            shelter: *shelter,
+            treasury_history: find_treasury_history_pda(shelter).0,
+            new_treasury: *treasury,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::UpdateTreasury { treasury: *treasury }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, update_treasury_ix, &[admin]).await
+}
+
+// Helper function to set the treasury timelock
+pub async fn set_treasury_timelock(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    timelock_secs: i64,
+) -> Result<(), BanksClientError> {
+    let set_treasury_timelock_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::SetTreasuryTimelock {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::SetTreasuryTimelock { timelock_secs }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, set_treasury_timelock_ix, &[admin]).await
+}
+
+// Helper function to find the PDA of a shelter's withdrawal record
+pub fn find_withdrawal_pda(shelter: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"withdrawal", shelter.as_ref(), &index.to_le_bytes()], &program_id())
+}
+
+// Helper function to withdraw funds from a shelter treasury, returning the withdrawal record.
+// The recipient's payee record is passed when it exists
+pub async fn withdraw_funds(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    shelter: &Pubkey,
+    dog: Option<&Pubkey>,
+    treasury: &Pubkey,
+    recipient: &Pubkey,
+    amount: u64,
+    category: SpendingCategory,
+    invoice_hash: [u8; 32],
+) -> Result<Pubkey, BanksClientError> {
+    let shelter_account: Shelter = fetch_account(banks_client, shelter).await;
+    let (withdrawal_pda, _) = find_withdrawal_pda(shelter, shelter_account.withdrawal_count);
+    let (payee_pda, _) = find_payee_pda(recipient);
+    let payee = banks_client.get_account(payee_pda).await?.map(|_| payee_pda);
+
+    let withdraw_funds_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::WithdrawFunds {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            shelter: *shelter,
+            dog: dog.copied(),
+            treasury: *treasury,
+            recipient: *recipient,
+            payee,
+            withdrawal: withdrawal_pda,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::WithdrawFunds {
+            amount,
+            category,
+            invoice_hash,
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, withdraw_funds_ix, &[admin]).await?;
+
+    Ok(withdrawal_pda)
+}
+
+// Helper function to find a payee PDA
+pub fn find_payee_pda(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"payee", wallet.as_ref()], &program_id())
+}
+
+// Helper function to register a payee
+pub async fn add_payee(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    wallet: &Pubkey,
+    label: &str,
+) -> Result<(), BanksClientError> {
+    let add_payee_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::AddPayee {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            payee: find_payee_pda(wallet).0,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::AddPayee {
+            wallet: *wallet,
+            label: label.to_string(),
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, add_payee_ix, &[admin]).await
+}
+
+// Helper function to remove a payee
+pub async fn remove_payee(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    wallet: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let remove_payee_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::RemovePayee {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            payee: find_payee_pda(wallet).0,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::RemovePayee {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, remove_payee_ix, &[admin]).await
+}
+
+// Helper function to set whether withdrawals require a registered payee
+pub async fn set_payee_policy(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    require_registered_payees: bool,
+) -> Result<(), BanksClientError> {
+    let set_payee_policy_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::SetPayeePolicy {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::SetPayeePolicy { require_registered_payees }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, set_payee_policy_ix, &[admin]).await
+}
+
+// Helper function to cap withdrawals per period
+pub async fn set_withdraw_limit(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    max_withdraw_per_period: u64,
+    withdraw_period_secs: i64,
+) -> Result<(), BanksClientError> {
+    let set_withdraw_limit_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::SetWithdrawLimit {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::SetWithdrawLimit {
+            max_withdraw_per_period,
+            withdraw_period_secs,
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, set_withdraw_limit_ix, &[admin]).await
+}
+
+// Helper function to simulate `reconcile` and decode the returned reconciliation
+pub async fn reconcile(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    shelter: &Pubkey,
+    treasury: &Pubkey,
+) -> woofi::Reconciliation {
+    let reconcile_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::Reconcile {
+            shelter: *shelter,
+            treasury: *treasury,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::Reconcile {}.data(),
+    };
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[reconcile_ix],
+        Some(&payer.pubkey()),
+        &[payer],
+        *recent_blockhash,
+    );
+
+    let simulation = banks_client.simulate_transaction(transaction).await.unwrap();
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .expect("reconcile should set return data");
+    woofi::Reconciliation::try_from_slice(&return_data.data).unwrap()
+}
+
+// Helper function to migrate the platform account to the current layout
+pub async fn migrate_platform(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+) -> Result<(), BanksClientError> {
+    let migrate_platform_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::MigratePlatform {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::MigratePlatform {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, migrate_platform_ix, &[admin]).await
+}
+
+// Helper function to migrate a dog account to the current layout
+pub async fn migrate_dog(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    dog: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let migrate_dog_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::MigrateDog {
+            payer: payer.pubkey(),
+            platform: find_platform_pda().0,
+            dog: *dog,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::MigrateDog {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, migrate_dog_ix, &[]).await
+}
+
+// Helper function to migrate a donation account to the current layout
+pub async fn migrate_donation(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    donation: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let migrate_donation_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::MigrateDonation {
+            payer: payer.pubkey(),
+            platform: find_platform_pda().0,
+            donation: *donation,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::MigrateDonation {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, migrate_donation_ix, &[]).await
+}
+
+// Helper function to assign a migrated dog from before shelters to a shelter
+pub async fn assign_legacy_dog(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    shelter: &Pubkey,
+    dog: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let platform: Platform = fetch_account(banks_client, &find_platform_pda().0).await;
+    let shelter_account: Shelter = fetch_account(banks_client, shelter).await;
+    let assign_legacy_dog_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::AssignLegacyDog {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            shelter: *shelter,
+            dog: *dog,
+            legacy_treasury: platform.treasury,
+            treasury: shelter_account.treasury,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::AssignLegacyDog {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, assign_legacy_dog_ix, &[admin]).await
+}
+
+// Helper function to update a dog, `edit` changes a copy of its current fields
+pub async fn update_dog(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    dog: &Pubkey,
+    edit: impl FnOnce(&mut Dog),
+) -> Result<(), BanksClientError> {
+    let mut current: Dog = fetch_account(banks_client, dog).await;
+    edit(&mut current);
+    let update_dog_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::UpdateDog {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            dog: *dog,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::UpdateDog {
+            image_url: current.image_url,
+            story: current.story,
+            active: current.active,
+            profile: current.profile,
+            intake_date: current.intake_date,
+            birth_date: current.birth_date,
+            metadata_uri: current.metadata_uri,
+            metadata_hash: current.metadata_hash,
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, update_dog_ix, &[admin]).await
+}
+
+// Helper function to find a dog's media gallery PDA
+pub fn find_dog_media_pda(dog: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"dog_media", dog.as_ref()], &program_id())
+}
+
+// Helper function to append an entry to a dog's gallery
+pub async fn add_dog_media(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    dog: &Pubkey,
+    entry: MediaEntry,
+) -> Result<(), BanksClientError> {
+    let add_dog_media_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::AddDogMedia {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            dog: *dog,
+            dog_media: find_dog_media_pda(dog).0,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::AddDogMedia {
+            kind: entry.kind,
+            uri: entry.uri,
+            content_hash: entry.content_hash,
+            caption: entry.caption,
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, add_dog_media_ix, &[admin]).await
+}
+
+// Helper function to remove an entry from a dog's gallery
+pub async fn remove_dog_media(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    dog: &Pubkey,
+    index: u8,
+) -> Result<(), BanksClientError> {
+    let remove_dog_media_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::RemoveDogMedia {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            dog: *dog,
+            dog_media: find_dog_media_pda(dog).0,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::RemoveDogMedia { index }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, remove_dog_media_ix, &[admin]).await
+}
+
+// Helper function to reorder a dog's gallery
+pub async fn reorder_dog_media(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    dog: &Pubkey,
+    order: Vec<u8>,
+) -> Result<(), BanksClientError> {
+    let reorder_dog_media_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::ReorderDogMedia {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            dog: *dog,
+            dog_media: find_dog_media_pda(dog).0,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::ReorderDogMedia { order }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, reorder_dog_media_ix, &[admin]).await
+}
+
+// Helper function to add a costed need to a dog
+pub async fn add_need(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    dog: &Pubkey,
+    category: SpendingCategory,
+    description: &str,
+    target: u64,
+) -> Result<(), BanksClientError> {
+    let add_need_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::AddNeed {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            dog: *dog,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::AddNeed {
+            category,
+            description: description.to_string(),
+            target,
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, add_need_ix, &[admin]).await
+}
+
+// Helper function to close one of a dog's needs
+pub async fn close_need(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    dog: &Pubkey,
+    index: u8,
+) -> Result<(), BanksClientError> {
+    let close_need_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::CloseNeed {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            dog: *dog,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::CloseNeed { index }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, close_need_ix, &[admin]).await
+}
+
+// Helper function to find a dog's medical record PDA
+pub fn find_medical_record_pda(dog: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"medical_record", dog.as_ref(), &index.to_le_bytes()], &program_id())
+}
+
+// Helper function to append a treatment to a dog's medical timeline
+pub async fn add_medical_record(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    curator_wallet: &Keypair,
+    is_curator: bool,
+    dog: &Pubkey,
+    clinic: &Pubkey,
+    withdrawal: Option<&Pubkey>,
+    date: i64,
+    procedure: &str,
+    cost: u64,
+    document_hash: [u8; 32],
+) -> Result<Pubkey, BanksClientError> {
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    let (medical_record_pda, _) = find_medical_record_pda(dog, dog_account.medical_record_count);
+
+    let add_medical_record_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::AddMedicalRecord {
+            curator_wallet: curator_wallet.pubkey(),
+            platform: find_platform_pda().0,
+            curator: is_curator.then(|| find_curator_pda(&curator_wallet.pubkey()).0),
+            dog: *dog,
+            clinic: find_payee_pda(clinic).0,
+            withdrawal: withdrawal.copied(),
+            medical_record: medical_record_pda,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::AddMedicalRecord {
+            date,
+            procedure: procedure.to_string(),
+            cost,
+            document_hash,
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, add_medical_record_ix, &[curator_wallet]).await?;
+
+    Ok(medical_record_pda)
+}
+
+// Helper function to find a dog's progress update PDA
+pub fn find_dog_update_pda(dog: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"dog_update", dog.as_ref(), &index.to_le_bytes()], &program_id())
+}
+
+// Helper function to post a progress update to a dog's feed
+pub async fn post_dog_update(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    author: &Keypair,
+    is_curator: bool,
+    dog: &Pubkey,
+    title: &str,
+    body: &str,
+    body_hash: [u8; 32],
+    media_uri: &str,
+) -> Result<Pubkey, BanksClientError> {
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    let (dog_update_pda, _) = find_dog_update_pda(dog, dog_account.update_count);
+
+    let post_dog_update_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::PostDogUpdate {
+            author: author.pubkey(),
+            platform: find_platform_pda().0,
+            curator: is_curator.then(|| find_curator_pda(&author.pubkey()).0),
+            dog: *dog,
+            dog_update: dog_update_pda,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::PostDogUpdate {
+            title: title.to_string(),
+            body: body.to_string(),
+            body_hash,
+            media_uri: media_uri.to_string(),
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, post_dog_update_ix, &[author]).await?;
+
+    Ok(dog_update_pda)
+}
+
+// Helper function to find an adoption application PDA
+pub fn find_adoption_pda(dog: &Pubkey, applicant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"adoption", dog.as_ref(), applicant.as_ref()], &program_id())
+}
+
+// Helper function to apply to adopt a dog
+pub async fn submit_adoption_application(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    applicant: &Keypair,
+    dog: &Pubkey,
+    contact_hash: [u8; 32],
+) -> Result<Pubkey, BanksClientError> {
+    let (application_pda, _) = find_adoption_pda(dog, &applicant.pubkey());
+
+    let submit_adoption_application_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::SubmitAdoptionApplication {
+            applicant: applicant.pubkey(),
+            platform: find_platform_pda().0,
+            dog: *dog,
+            application: application_pda,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::SubmitAdoptionApplication { contact_hash }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, submit_adoption_application_ix, &[applicant]).await?;
+
+    Ok(application_pda)
+}
+
+// Helper function to move an adoption application to `status`
+pub async fn review_adoption_application(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    reviewer: &Keypair,
+    is_curator: bool,
+    dog: &Pubkey,
+    application: &Pubkey,
+    status: ApplicationStatus,
+) -> Result<(), BanksClientError> {
+    let review_adoption_application_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::ReviewAdoptionApplication {
+            reviewer: reviewer.pubkey(),
+            platform: find_platform_pda().0,
+            curator: is_curator.then(|| find_curator_pda(&reviewer.pubkey()).0),
+            dog: *dog,
+            application: *application,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::ReviewAdoptionApplication { status }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, review_adoption_application_ix, &[reviewer]).await
+}
+
+// Helper function to find a foster PDA
+pub fn find_foster_pda(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"foster", wallet.as_ref()], &program_id())
+}
+
+// Helper function to register a foster volunteer and verify them if `verified`
+pub async fn register_foster(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    wallet: &Keypair,
+    region: &str,
+    capacity: u8,
+    verified: bool,
+) -> Result<Pubkey, BanksClientError> {
+    let (foster_pda, _) = find_foster_pda(&wallet.pubkey());
+
+    let register_foster_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::RegisterFoster {
+            wallet: wallet.pubkey(),
+            platform: find_platform_pda().0,
+            foster: foster_pda,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::RegisterFoster {
+            region: region.to_string(),
+            capacity,
+        }
+        .data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, register_foster_ix, &[wallet]).await?;
+
+    if verified {
+        let verify_foster_ix = Instruction {
+            program_id: program_id(),
+            accounts: woofi::accounts::VerifyFoster {
+                admin: admin.pubkey(),
+                platform: find_platform_pda().0,
+                foster: foster_pda,
+            }
+            .to_account_metas(None),
+            data: woofi::instruction::VerifyFoster {}.data(),
+        };
+
+        process_instruction(banks_client, payer, recent_blockhash, verify_foster_ix, &[admin]).await?;
+    }
+
+    Ok(foster_pda)
+}
+
+// Helper function to place a dog with a foster
+pub async fn assign_foster(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    dog: &Pubkey,
+    foster: &Pubkey,
+    allow_payouts: bool,
+) -> Result<(), BanksClientError> {
+    let assign_foster_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::AssignFoster {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            dog: *dog,
+            foster: *foster,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::AssignFoster { allow_payouts }.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, assign_foster_ix, &[admin]).await
+}
+
+// Helper function to end a dog's stay with its foster
+pub async fn end_foster(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    signer: &Keypair,
+    dog: &Pubkey,
+    foster: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let end_foster_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::EndFoster {
+            signer: signer.pubkey(),
+            platform: find_platform_pda().0,
+            dog: *dog,
+            foster: *foster,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::EndFoster {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, end_foster_ix, &[signer]).await
+}
+
+// Helper function to find a dog's pending transfer PDA
+pub fn find_dog_transfer_pda(dog: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"dog_transfer", dog.as_ref()], &program_id())
+}
+
+// Helper function to propose moving a dog to another shelter
+pub async fn propose_dog_transfer(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    from_shelter: &Pubkey,
+    to_shelter: &Pubkey,
+    dog: &Pubkey,
+) -> Result<Pubkey, BanksClientError> {
+    let (transfer_pda, _) = find_dog_transfer_pda(dog);
+
+    let propose_dog_transfer_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::ProposeDogTransfer {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            from_shelter: *from_shelter,
+            to_shelter: *to_shelter,
+            dog: *dog,
+            transfer: transfer_pda,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::ProposeDogTransfer {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, propose_dog_transfer_ix, &[admin]).await?;
+
+    Ok(transfer_pda)
+}
+
+// Helper function to accept a dog's pending transfer, filling in the shelters and
+// treasuries from the proposal
+pub async fn accept_dog_transfer(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    dog: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (transfer_pda, _) = find_dog_transfer_pda(dog);
+    let transfer: DogTransfer = fetch_account(banks_client, &transfer_pda).await;
+    let from_shelter: Shelter = fetch_account(banks_client, &transfer.from_shelter).await;
+    let to_shelter: Shelter = fetch_account(banks_client, &transfer.to_shelter).await;
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    let (withdrawal_pda, _) = find_withdrawal_pda(&transfer.from_shelter, from_shelter.withdrawal_count);
+
+    let accept_dog_transfer_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::AcceptDogTransfer {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            from_shelter: transfer.from_shelter,
+            to_shelter: transfer.to_shelter,
+            dog: *dog,
+            foster: dog_account.foster.map(|wallet| find_foster_pda(&wallet).0),
+            from_treasury: from_shelter.treasury,
+            to_treasury: to_shelter.treasury,
+            proposer: transfer.proposed_by,
+            transfer: transfer_pda,
+            withdrawal: withdrawal_pda,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::AcceptDogTransfer {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, accept_dog_transfer_ix, &[admin]).await
+}
+
+// Helper function to cancel a dog's pending transfer
+pub async fn cancel_dog_transfer(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: &solana_sdk::hash::Hash,
+    admin: &Keypair,
+    dog: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (transfer_pda, _) = find_dog_transfer_pda(dog);
+    let transfer: DogTransfer = fetch_account(banks_client, &transfer_pda).await;
+
+    let cancel_dog_transfer_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::CancelDogTransfer {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            to_shelter: transfer.to_shelter,
+            proposer: transfer.proposed_by,
+            transfer: transfer_pda,
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::CancelDogTransfer {}.data(),
+    };
+
+    process_instruction(banks_client, payer, recent_blockhash, cancel_dog_transfer_ix, &[admin]).await
+}
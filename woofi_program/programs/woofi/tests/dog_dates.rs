@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use woofi::{state::Dog, SECONDS_PER_YEAR};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_new_dog_dates() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, dog, .. } = &mut env;
+
+    let clock: Clock = banks_client.get_sysvar().await.unwrap();
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.intake_date, clock.unix_timestamp, "Intake should default to now");
+    assert_eq!(dog_account.birth_date, 0, "Birth date should be unknown");
+    assert_eq!(dog_account.age_years(clock.unix_timestamp), None, "Age should be unknown");
+}
+
+#[tokio::test]
+async fn test_age_follows_birth_date() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    let intake_date = dog_account.intake_date;
+    update_dog(banks_client, payer, recent_blockhash, admin, dog, |dog| {
+        dog.birth_date = intake_date - 3 * SECONDS_PER_YEAR;
+    })
+    .await
+    .expect("Failed to set birth date");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.age, 3, "Age at intake mismatch");
+    assert_eq!(dog_account.age_years(intake_date), Some(3), "Age mismatch");
+    assert_eq!(dog_account.age_years(intake_date + 2 * SECONDS_PER_YEAR), Some(5), "Dogs should get older");
+}
+
+#[tokio::test]
+async fn test_invalid_dates() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    // Should fail because the dog cannot be born after its intake
+    let result = update_dog(banks_client, payer, recent_blockhash, admin, dog, |dog| {
+        dog.birth_date = dog.intake_date + 1;
+    })
+    .await;
+    assert!(result.is_err(), "Birth dates after intake should be rejected");
+
+    // Should fail because the intake is in the future
+    let result = update_dog(banks_client, payer, recent_blockhash, admin, dog, |dog| {
+        dog.intake_date += SECONDS_PER_YEAR;
+    })
+    .await;
+    assert!(result.is_err(), "Future intake dates should be rejected");
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use woofi::{
+    state::{DogMedia, MediaEntry, MediaKind},
+    MAX_DOG_MEDIA,
+};
+
+mod common;
+use common::*;
+
+fn media(uri: &str) -> MediaEntry {
+    MediaEntry {
+        kind: MediaKind::Image,
+        uri: uri.to_string(),
+        content_hash: [7; 32],
+        caption: "Playing in the yard".to_string(),
+    }
+}
+
+fn uris(dog_media: &DogMedia) -> Vec<&str> {
+    dog_media.entries.iter().map(|entry| entry.uri.as_str()).collect()
+}
+
+#[tokio::test]
+async fn test_manage_gallery() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    for uri in ["https://example.com/rex.png", "ipfs://bafyrex", "ar://rexvideo"] {
+        add_dog_media(banks_client, payer, recent_blockhash, admin, dog, media(uri))
+            .await
+            .expect("Failed to add media");
+    }
+    let dog_media: DogMedia = fetch_account(banks_client, &find_dog_media_pda(dog).0).await;
+    assert_eq!(dog_media.dog, *dog, "Gallery dog mismatch");
+    assert_eq!(uris(&dog_media), ["https://example.com/rex.png", "ipfs://bafyrex", "ar://rexvideo"]);
+
+    reorder_dog_media(banks_client, payer, recent_blockhash, admin, dog, vec![2, 0, 1])
+        .await
+        .expect("Failed to reorder media");
+    let dog_media: DogMedia = fetch_account(banks_client, &find_dog_media_pda(dog).0).await;
+    assert_eq!(uris(&dog_media), ["ar://rexvideo", "https://example.com/rex.png", "ipfs://bafyrex"]);
+
+    remove_dog_media(banks_client, payer, recent_blockhash, admin, dog, 1)
+        .await
+        .expect("Failed to remove media");
+    let dog_media: DogMedia = fetch_account(banks_client, &find_dog_media_pda(dog).0).await;
+    assert_eq!(uris(&dog_media), ["ar://rexvideo", "ipfs://bafyrex"]);
+
+    // Should fail because the order repeats an entry
+    let result = reorder_dog_media(banks_client, payer, recent_blockhash, admin, dog, vec![0, 0]).await;
+    assert!(result.is_err(), "Orders must be a permutation of the gallery");
+
+    // Should fail because the index is past the end
+    let result = remove_dog_media(banks_client, payer, recent_blockhash, admin, dog, 2).await;
+    assert!(result.is_err(), "Out of range indexes should be rejected");
+}
+
+#[tokio::test]
+async fn test_invalid_media() {
+    let (mut env, [stranger]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    // Should fail because plain http is not supported
+    let result = add_dog_media(banks_client, payer, recent_blockhash, admin, dog, media("http://example.com/rex.png")).await;
+    assert!(result.is_err(), "Unsupported URI schemes should be rejected");
+
+    // Should fail because the content hash is missing
+    let unhashed = MediaEntry { content_hash: [0; 32], ..media("https://example.com/rex.png") };
+    let result = add_dog_media(banks_client, payer, recent_blockhash, admin, dog, unhashed).await;
+    assert!(result.is_err(), "Media without a content hash should be rejected");
+
+    // Should fail because the stranger does not manage the dog
+    let result = add_dog_media(banks_client, payer, recent_blockhash, &stranger, dog, media("https://example.com/rex.png")).await;
+    assert!(result.is_err(), "Only the dog's admin can add media");
+}
+
+#[tokio::test]
+async fn test_gallery_limit() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    for index in 0..MAX_DOG_MEDIA {
+        add_dog_media(banks_client, payer, recent_blockhash, admin, dog, media(&format!("ipfs://rex{}", index)))
+            .await
+            .expect("Failed to add media");
+    }
+
+    // Should fail because the gallery is full
+    let result = add_dog_media(banks_client, payer, recent_blockhash, admin, dog, media("ipfs://one-more")).await;
+    assert!(result.is_err(), "Full galleries should reject new media");
+}
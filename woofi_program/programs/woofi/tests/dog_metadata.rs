@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use woofi::state::{Dog, DogProfile};
+
+mod common;
+use common::*;
+
+const METADATA_URI: &str = "ipfs://bafybeigdyrztbella";
+const METADATA_HASH: [u8; 32] = [42; 32];
+
+#[tokio::test]
+async fn test_story_from_metadata() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, shelter, .. } = &mut env;
+
+    add_dog(
+        banks_client, payer, recent_blockhash, admin, shelter,
+        "Bella", 0, 0, "https://example.com/bella.png", "", DogProfile::default(),
+        METADATA_URI, METADATA_HASH,
+    )
+    .await
+    .expect("Failed to add dog with metadata");
+
+    let dog: Dog = fetch_account(banks_client, &find_dog_pda(shelter, "Bella").0).await;
+    assert_eq!(dog.story, "", "Story should be left to the metadata");
+    assert_eq!(dog.metadata_uri, METADATA_URI, "Metadata URI mismatch");
+    assert_eq!(dog.metadata_hash, METADATA_HASH, "Metadata hash mismatch");
+
+    // Should fail because there is neither a story nor a metadata document
+    let result = add_dog(
+        banks_client, payer, recent_blockhash, admin, shelter,
+        "Max", 0, 0, "https://example.com/max.png", "", DogProfile::default(),
+        "", [0; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Dogs need a story or a metadata document");
+}
+
+#[tokio::test]
+async fn test_invalid_metadata() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    update_dog(banks_client, payer, recent_blockhash, admin, dog, |dog| {
+        dog.metadata_uri = METADATA_URI.to_string();
+        dog.metadata_hash = METADATA_HASH;
+    })
+    .await
+    .expect("Failed to set metadata");
+
+    // Should fail because the document is not committed to by a hash
+    let result = update_dog(banks_client, payer, recent_blockhash, admin, dog, |dog| {
+        dog.metadata_hash = [0; 32];
+    })
+    .await;
+    assert!(result.is_err(), "Metadata without a hash should be rejected");
+
+    // Should fail because plain http is not supported
+    let result = update_dog(banks_client, payer, recent_blockhash, admin, dog, |dog| {
+        dog.metadata_uri = "http://example.com/rex.json".to_string();
+    })
+    .await;
+    assert!(result.is_err(), "Unsupported URI schemes should be rejected");
+
+    // Should fail because a hash without a document means nothing
+    let result = update_dog(banks_client, payer, recent_blockhash, admin, dog, |dog| {
+        dog.metadata_uri = String::new();
+    })
+    .await;
+    assert!(result.is_err(), "A hash without a URI should be rejected");
+}
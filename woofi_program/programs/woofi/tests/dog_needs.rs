@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use woofi::state::{Dog, Donation, SpendingCategory};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_donations_fund_needs() {
+    let (mut env, [donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    add_need(banks_client, payer, recent_blockhash, admin, dog, SpendingCategory::Medical, "X-ray", 2_000_000)
+        .await
+        .expect("Failed to add need");
+    add_need(banks_client, payer, recent_blockhash, admin, dog, SpendingCategory::Food, "Puppy food", 500_000)
+        .await
+        .expect("Failed to add need");
+    add_need(banks_client, payer, recent_blockhash, admin, dog, SpendingCategory::Other, "Dog bed", 300_000)
+        .await
+        .expect("Failed to add need");
+
+    // The legacy flags follow the open needs
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert!(dog_account.needs_medical && dog_account.needs_food, "Open needs should set their flags");
+    assert!(!dog_account.needs_toys && !dog_account.needs_shelter, "Categories without needs stay unset");
+    assert_eq!(dog_account.needs_other, "Dog bed", "Other needs should be described");
+
+    let donation = donate_to_need(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_500_000, "For the X-ray", 1, Some(0),
+    )
+    .await
+    .expect("Failed to donate to need");
+    let donation_account: Donation = fetch_account(banks_client, &donation).await;
+    assert_eq!(donation_account.need_index, Some(0), "Donation should be earmarked");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.needs[0].raised, 1_500_000, "Raised amount mismatch");
+    assert!(!dog_account.needs[0].fulfilled, "Need should still be open");
+    assert_eq!(dog_account.total_donations, 1_500_000, "Earmarked donations count for the dog");
+
+    donate_to_need(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        500_000, "", 2, Some(0),
+    )
+    .await
+    .expect("Failed to donate to need");
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert!(dog_account.needs[0].fulfilled, "Need should be fulfilled at its target");
+    assert!(!dog_account.needs_medical, "Fulfilled needs should clear their flag");
+    assert!(dog_account.needs_food, "Other open needs keep their flag");
+
+    // Should fail because the need is fulfilled
+    let result = donate_to_need(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        100_000, "", 3, Some(0),
+    )
+    .await;
+    assert!(result.is_err(), "Fulfilled needs cannot be donated to");
+
+    // Should fail because there is no such need
+    let result = donate_to_need(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        100_000, "", 4, Some(3),
+    )
+    .await;
+    assert!(result.is_err(), "Unknown needs cannot be donated to");
+}
+
+#[tokio::test]
+async fn test_close_need() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    add_need(banks_client, payer, recent_blockhash, admin, dog, SpendingCategory::Shelter, "Heated kennel", 1_000_000)
+        .await
+        .expect("Failed to add need");
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert!(dog_account.needs_shelter, "Open needs should set their flag");
+
+    close_need(banks_client, payer, recent_blockhash, admin, dog, 0)
+        .await
+        .expect("Failed to close need");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert!(dog_account.needs[0].fulfilled, "Closed needs are fulfilled");
+    assert_eq!(dog_account.needs[0].raised, 0, "Nothing was raised");
+    assert!(!dog_account.needs_shelter, "Closed needs should clear their flag");
+
+    // Should fail because the need is already closed
+    let result = close_need(banks_client, payer, recent_blockhash, admin, dog, 0).await;
+    assert!(result.is_err(), "Needs cannot be closed twice");
+
+    // Should fail because there is no target
+    let result = add_need(banks_client, payer, recent_blockhash, admin, dog, SpendingCategory::Toys, "Ball", 0).await;
+    assert!(result.is_err(), "Needs require a target");
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use woofi::{
+    state::{Dog, DogProfile, DogSex, DogSize, SterilizationStatus, VaccinationStatus},
+    MAX_BREED_LEN, MAX_DOG_WEIGHT_GRAMS,
+};
+
+mod common;
+use common::*;
+
+fn profile() -> DogProfile {
+    DogProfile {
+        breed: "Labrador mix".to_string(),
+        sex: DogSex::Male,
+        size: DogSize::Large,
+        weight_grams: 28_000,
+        vaccination: VaccinationStatus::UpToDate,
+        sterilization: SterilizationStatus::Sterilized,
+        location: "Lisbon".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_new_dog_has_empty_profile() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, dog, .. } = &mut env;
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.profile, DogProfile::default(), "Profile should default to unknown");
+}
+
+#[tokio::test]
+async fn test_update_dog_profile() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    update_dog(banks_client, payer, recent_blockhash, admin, dog, |dog| dog.profile = profile())
+        .await
+        .expect("Failed to update profile");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.profile, profile(), "Profile mismatch");
+    assert_eq!(dog_account.name, "Rex", "Other fields should be kept");
+}
+
+#[tokio::test]
+async fn test_invalid_profile() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    // Should fail because the breed is too long
+    let long_breed = DogProfile { breed: "a".repeat(MAX_BREED_LEN + 1), ..profile() };
+    let result = update_dog(banks_client, payer, recent_blockhash, admin, dog, |dog| dog.profile = long_breed).await;
+    assert!(result.is_err(), "Breeds over the limit should be rejected");
+
+    // Should fail because no dog weighs this much
+    let heavy = DogProfile { weight_grams: MAX_DOG_WEIGHT_GRAMS + 1, ..profile() };
+    let result = update_dog(banks_client, payer, recent_blockhash, admin, dog, |dog| dog.profile = heavy).await;
+    assert!(result.is_err(), "Implausible weights should be rejected");
+}
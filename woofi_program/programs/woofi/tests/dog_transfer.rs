@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use woofi::state::{Dog, Foster, Platform, Shelter, SpendingCategory, Withdrawal};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_transfer_dog() {
+    let (mut env, [rescue_admin, rescue_treasury, donor, volunteer]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    let rescue = register_shelter(
+        banks_client, payer, recent_blockhash, admin, "Second Chance", &rescue_treasury.pubkey(), &rescue_admin.pubkey(),
+    )
+    .await
+    .expect("Failed to register shelter");
+    donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "Good boy", 1,
+    )
+    .await
+    .expect("Failed to donate");
+    let foster = register_foster(banks_client, payer, recent_blockhash, admin, &volunteer, "Lisbon", 1, true)
+        .await
+        .expect("Failed to register foster");
+    assign_foster(banks_client, payer, recent_blockhash, admin, dog, &foster, true)
+        .await
+        .expect("Failed to assign foster");
+
+    // Should fail because only the source shelter's admin can propose a transfer
+    let result = propose_dog_transfer(banks_client, payer, recent_blockhash, &rescue_admin, shelter, &rescue, dog).await;
+    assert!(result.is_err(), "Only the owning shelter can give a dog away");
+
+    // Should fail because the dog already belongs to this shelter
+    let result = propose_dog_transfer(banks_client, payer, recent_blockhash, admin, shelter, shelter, dog).await;
+    assert!(result.is_err(), "Dogs must move to a different shelter");
+
+    let transfer = propose_dog_transfer(banks_client, payer, recent_blockhash, admin, shelter, &rescue, dog)
+        .await
+        .expect("Failed to propose transfer");
+
+    // Should fail because only the destination shelter's admin can accept
+    let result = accept_dog_transfer(banks_client, payer, recent_blockhash, admin, dog).await;
+    assert!(result.is_err(), "The source shelter cannot accept its own proposal");
+
+    let platform_before: Platform = fetch_account(banks_client, &find_platform_pda().0).await;
+    let source_before = banks_client.get_balance(treasury.pubkey()).await.unwrap();
+    let destination_before = banks_client.get_balance(rescue_treasury.pubkey()).await.unwrap();
+    accept_dog_transfer(banks_client, payer, recent_blockhash, &rescue_admin, dog)
+        .await
+        .expect("Failed to accept transfer");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.owner_shelter, rescue, "Dog should belong to the new shelter");
+    assert_eq!(dog_account.admin, rescue_admin.pubkey(), "Dog admin should follow the shelter");
+    assert_eq!(dog_account.total_donations, 1_000_000, "Dog keeps its donation history");
+    assert_eq!(dog_account.foster, None, "Foster placement should end with the transfer");
+    assert!(!dog_account.foster_payouts, "Foster payouts should be revoked");
+    let foster_account: Foster = fetch_account(banks_client, &foster).await;
+    assert_eq!(foster_account.dog_count, 0, "Foster should no longer count the dog");
+
+    let source_after = banks_client.get_balance(treasury.pubkey()).await.unwrap();
+    let destination_after = banks_client.get_balance(rescue_treasury.pubkey()).await.unwrap();
+    assert_eq!(source_before - source_after, 1_000_000, "Balance should leave the source treasury");
+    assert_eq!(destination_after - destination_before, 1_000_000, "Balance should reach the new treasury");
+
+    let source: Shelter = fetch_account(banks_client, shelter).await;
+    assert_eq!(source.dog_count, 0, "Source shelter dog count mismatch");
+    assert_eq!(source.booked_balance(), 0, "Source shelter should have nothing booked");
+    assert_eq!(source.transferred_out, 1_000_000, "Source shelter should book the transfer");
+    assert_eq!(source.total_withdrawn, 0, "Transfers are not spending");
+    assert_eq!(source.period_withdrawn, 0, "Transfers should not count against the withdrawal cap");
+    assert_eq!(source.withdrawal_count, 1, "Transfer should be kept in the withdrawal history");
+    let destination: Shelter = fetch_account(banks_client, &rescue).await;
+    assert_eq!(destination.dog_count, 1, "Destination shelter dog count mismatch");
+    assert_eq!(destination.booked_balance(), 1_000_000, "Destination shelter should book the balance");
+    assert_eq!(destination.transferred_in, 1_000_000, "Destination shelter should book the transfer");
+    assert_eq!(destination.total_donations, 0, "Transfers are not donations");
+
+    let withdrawal: Withdrawal = fetch_account(banks_client, &find_withdrawal_pda(shelter, 0).0).await;
+    assert_eq!(withdrawal.dog, Some(*dog), "Record should name the dog");
+    assert_eq!(withdrawal.amount, 1_000_000, "Record amount mismatch");
+    assert_eq!(withdrawal.recipient, rescue_treasury.pubkey(), "Record should name the new treasury");
+    assert_eq!(withdrawal.category, SpendingCategory::Transfer, "Record category mismatch");
+
+    let platform_after: Platform = fetch_account(banks_client, &find_platform_pda().0).await;
+    assert_eq!(platform_after.total_withdrawn, platform_before.total_withdrawn, "Platform withdrawals should be unchanged");
+    assert_eq!(platform_after.total_donations, platform_before.total_donations, "Platform donations should be unchanged");
+
+    // Both treasuries still match their books
+    let reconciliation = reconcile(banks_client, payer, recent_blockhash, shelter, &treasury.pubkey()).await;
+    assert_eq!(reconciliation.discrepancy, 0, "Source shelter should reconcile");
+    let reconciliation = reconcile(banks_client, payer, recent_blockhash, &rescue, &rescue_treasury.pubkey()).await;
+    assert_eq!(reconciliation.discrepancy, 0, "Destination shelter should reconcile");
+
+    let transfer_info = banks_client.get_account(transfer).await.unwrap();
+    assert!(transfer_info.is_none(), "Transfer should be closed");
+
+    // Should fail because the old shelter no longer owns the dog
+    let result = propose_dog_transfer(banks_client, payer, recent_blockhash, admin, shelter, &rescue, dog).await;
+    assert!(result.is_err(), "Former shelters cannot move the dog");
+}
+
+#[tokio::test]
+async fn test_transfer_ignores_withdraw_limit() {
+    let (mut env, [rescue_admin, rescue_treasury, donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    let rescue = register_shelter(
+        banks_client, payer, recent_blockhash, admin, "Second Chance", &rescue_treasury.pubkey(), &rescue_admin.pubkey(),
+    )
+    .await
+    .expect("Failed to register shelter");
+    donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "Good boy", 1,
+    )
+    .await
+    .expect("Failed to donate");
+    set_withdraw_limit(banks_client, payer, recent_blockhash, admin, 500_000, 86_400)
+        .await
+        .expect("Failed to set withdraw limit");
+    propose_dog_transfer(banks_client, payer, recent_blockhash, admin, shelter, &rescue, dog)
+        .await
+        .expect("Failed to propose transfer");
+
+    // The balance exceeds the source shelter's withdrawal cap, which only limits spending
+    accept_dog_transfer(banks_client, payer, recent_blockhash, &rescue_admin, dog)
+        .await
+        .expect("Failed to accept transfer");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.owner_shelter, rescue, "Dog should belong to the new shelter");
+    let source: Shelter = fetch_account(banks_client, shelter).await;
+    assert_eq!(source.period_withdrawn, 0, "Transfers should not use up the withdrawal cap");
+}
+
+#[tokio::test]
+async fn test_transfer_category_is_reserved() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    // Should fail because transfers are only recorded when a dog moves
+    let result = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, Some(dog), &treasury.pubkey(), &Keypair::new().pubkey(),
+        1_000_000, SpendingCategory::Transfer, [1; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Withdrawals cannot be filed as transfers");
+
+    // Should fail because transfers are not something a dog needs
+    let result = add_need(banks_client, payer, recent_blockhash, admin, dog, SpendingCategory::Transfer, "Move", 1_000_000).await;
+    assert!(result.is_err(), "Needs cannot be transfers");
+}
+
+#[tokio::test]
+async fn test_cancel_dog_transfer() {
+    let (mut env, [rescue_admin, stranger]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, shelter, dog, .. } = &mut env;
+
+    let rescue = register_shelter(
+        banks_client, payer, recent_blockhash, admin, "Second Chance", &Keypair::new().pubkey(), &rescue_admin.pubkey(),
+    )
+    .await
+    .expect("Failed to register shelter");
+    let transfer = propose_dog_transfer(banks_client, payer, recent_blockhash, admin, shelter, &rescue, dog)
+        .await
+        .expect("Failed to propose transfer");
+
+    // Should fail because a transfer is already pending
+    let result = propose_dog_transfer(banks_client, payer, recent_blockhash, admin, shelter, &rescue, dog).await;
+    assert!(result.is_err(), "Only one transfer can be pending");
+
+    // Should fail because the signer is on neither side of the transfer
+    let result = cancel_dog_transfer(banks_client, payer, recent_blockhash, &stranger, dog).await;
+    assert!(result.is_err(), "Strangers cannot cancel a transfer");
+
+    cancel_dog_transfer(banks_client, payer, recent_blockhash, &rescue_admin, dog)
+        .await
+        .expect("Failed to decline transfer");
+    let transfer_info = banks_client.get_account(transfer).await.unwrap();
+    assert!(transfer_info.is_none(), "Transfer should be closed");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.owner_shelter, *shelter, "Dog should stay with its shelter");
+
+    propose_dog_transfer(banks_client, payer, recent_blockhash, admin, shelter, &rescue, dog)
+        .await
+        .expect("A declined transfer can be proposed again");
+}
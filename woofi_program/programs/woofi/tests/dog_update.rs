@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+use woofi::state::{Dog, DogUpdate};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_dog_news_feed() {
+    let (mut env, [curator]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    add_curator(banks_client, payer, recent_blockhash, admin, &curator.pubkey())
+        .await
+        .expect("Failed to add curator");
+
+    let first = post_dog_update(
+        banks_client, payer, recent_blockhash, &curator, true, dog,
+        "Surgery went well", "Rex is recovering and already wagging his tail.", [0; 32], "ipfs://bafyrexrecovery",
+    )
+    .await
+    .expect("Failed to post update");
+    let second = post_dog_update(
+        banks_client, payer, recent_blockhash, admin, false, dog,
+        "First walk", "", [9; 32], "",
+    )
+    .await
+    .expect("Failed to post update");
+
+    let update: DogUpdate = fetch_account(banks_client, &first).await;
+    assert_eq!(update.index, 0, "Update index mismatch");
+    assert_eq!(update.author, curator.pubkey(), "Author mismatch");
+    assert_eq!(update.media_uri, "ipfs://bafyrexrecovery", "Media mismatch");
+    assert!(update.posted_at > 0, "Updates are timestamped");
+
+    let update: DogUpdate = fetch_account(banks_client, &second).await;
+    assert_eq!(update.index, 1, "Updates should form a series");
+    assert_eq!(update.body_hash, [9; 32], "Body hash mismatch");
+
+    // Updates are kept alongside the dog's current story
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.update_count, 2, "Update count mismatch");
+    assert_eq!(dog_account.story, "Found on the street", "Story should be unchanged");
+}
+
+#[tokio::test]
+async fn test_invalid_dog_updates() {
+    let (mut env, [stranger]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    // Should fail because the author does not manage the dog
+    let result = post_dog_update(banks_client, payer, recent_blockhash, &stranger, false, dog, "Hello", "Hi", [0; 32], "").await;
+    assert!(result.is_err(), "Only the dog's admin or curators can post updates");
+
+    // Should fail because there is neither a body nor a body hash
+    let result = post_dog_update(banks_client, payer, recent_blockhash, admin, false, dog, "Hello", "", [0; 32], "").await;
+    assert!(result.is_err(), "Updates need a body");
+
+    // Should fail because plain http is not supported
+    let result = post_dog_update(
+        banks_client, payer, recent_blockhash, admin, false, dog, "Hello", "Hi", [0; 32], "http://example.com/rex.png",
+    )
+    .await;
+    assert!(result.is_err(), "Unsupported media URIs should be rejected");
+}
@@ -36,13 +36,17 @@ async fn donate_ix_success() {
 	let amount: u64 = Default::default();
 	let message: String = Default::default();
 	let timestamp: i64 = Default::default();
+	let anonymous: bool = Default::default();
+	let need_index: Option<u8> = Default::default();
 
 	// KEYPAIR
 	let donor_keypair = Keypair::new();
+	let shelter_keypair = Keypair::new();
 	let dog_keypair = Keypair::new();
 
 	// PUBKEY
 	let donor_pubkey = donor_keypair.pubkey();
+	let shelter_pubkey = shelter_keypair.pubkey();
 	let dog_pubkey = dog_keypair.pubkey();
 	let treasury_pubkey = Pubkey::new_unique();
 
@@ -57,6 +61,13 @@ async fn donate_ix_success() {
 		&woofi::ID,
 	);
 
+	let (moderation_pda, _moderation_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"moderation",
+		],
+		&woofi::ID,
+	);
+
 	let (donation_pda, _donation_pda_bump) = Pubkey::find_program_address(
 		&[
 			b"donation",
@@ -84,13 +95,17 @@ async fn donate_ix_success() {
 	let ix = woofi_ix_interface::donate_ix_setup(
 		&donor_keypair,
 		platform_pda,
+		shelter_pubkey,
 		dog_pubkey,
 		donation_pda,
 		treasury_pubkey,
+		moderation_pda,
 		system_program_pubkey,
 		amount,
 		&message,
 		timestamp,
+		anonymous,
+		need_index,
 		recent_blockhash,
 	);
 
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use woofi::{state::{DogProfile, Donation}, MESSAGE_EDIT_WINDOW_SECONDS};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_edit_donation_message() {
+    let (mut env, [donor, stranger]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, treasury, shelter, dog, .. } = &mut env;
+
+    let donation = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "Good boy", 1,
+    )
+    .await
+    .expect("Failed to donate");
+
+    let result = edit_donation_message(banks_client, payer, recent_blockhash, &donor, &donation, "Very good boy").await;
+    assert!(result.is_ok(), "Failed to edit message: {:?}", result);
+
+    let donation_account: Donation = fetch_account(banks_client, &donation).await;
+    assert_eq!(donation_account.message, "Very good boy", "Message mismatch");
+    assert_ne!(donation_account.message_edited_at, 0, "Edit time should be recorded");
+
+    // Should fail because only the donor can edit their message
+    let result = edit_donation_message(banks_client, payer, recent_blockhash, &stranger, &donation, "Spam").await;
+    assert!(result.is_err(), "Only the donor can edit the message");
+}
+
+#[tokio::test]
+async fn test_edit_donation_message_after_window() {
+    let mut context = setup_program_test().start_with_context().await;
+    let admin = Keypair::new();
+    let donor = Keypair::new();
+    let treasury = Keypair::new();
+    let payer = context.payer.insecure_clone();
+    let recent_blockhash = context.last_blockhash;
+    airdrop(&mut context.banks_client, &payer, &recent_blockhash, &admin.pubkey(), 10_000_000_000).await;
+    airdrop(&mut context.banks_client, &payer, &recent_blockhash, &donor.pubkey(), 10_000_000_000).await;
+
+    create_platform(&mut context.banks_client, &payer, &recent_blockhash, &admin, &Keypair::new().pubkey())
+        .await
+        .expect("Failed to initialize platform");
+    let shelter = register_shelter(&mut context.banks_client, &payer, &recent_blockhash, &admin, "Happy Paws", &treasury.pubkey(), &admin.pubkey())
+        .await
+        .expect("Failed to register shelter");
+    add_dog(
+        &mut context.banks_client, &payer, &recent_blockhash, &admin, &shelter,
+        "Rex", 0, 0, "https://example.com/rex.png", "Found on the street", DogProfile::default(), "", [0; 32],
+    )
+    .await
+    .expect("Failed to add dog");
+    let donation = donate(
+        &mut context.banks_client, &payer, &recent_blockhash, &donor, &shelter, &find_dog_pda(&shelter, "Rex").0, &treasury.pubkey(),
+        1_000_000, "Good boy", 1,
+    )
+    .await
+    .expect("Failed to donate");
+
+    // Move the clock past the edit window
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += MESSAGE_EDIT_WINDOW_SECONDS + 1;
+    context.set_sysvar(&clock);
+
+    // Should fail because the edit window has closed
+    let result = edit_donation_message(&mut context.banks_client, &payer, &recent_blockhash, &donor, &donation, "").await;
+    assert!(result.is_err(), "Edits after the window should be rejected");
+}
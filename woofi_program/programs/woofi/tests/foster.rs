@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+use woofi::state::{Dog, DogProfile, Foster, SpendingCategory};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_foster_assignment() {
+    let (mut env, [volunteer, unverified]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, shelter, dog, .. } = &mut env;
+    add_dog(
+        banks_client, payer, recent_blockhash, admin, shelter,
+        "Buddy", 0, 0, "https://example.com/buddy.png", "Found in a park", DogProfile::default(),
+        "", [0; 32],
+    )
+    .await
+    .expect("Failed to add dog");
+    let (buddy, _) = find_dog_pda(shelter, "Buddy");
+
+    // Should fail because fosters need room for at least one dog
+    let result = register_foster(banks_client, payer, recent_blockhash, admin, &volunteer, "Lisbon", 0, true).await;
+    assert!(result.is_err(), "Capacity must be positive");
+
+    let foster = register_foster(banks_client, payer, recent_blockhash, admin, &volunteer, "Lisbon", 1, true)
+        .await
+        .expect("Failed to register foster");
+    let unverified_foster = register_foster(banks_client, payer, recent_blockhash, admin, &unverified, "Porto", 2, false)
+        .await
+        .expect("Failed to register foster");
+
+    // Should fail because the foster has not been verified
+    let result = assign_foster(banks_client, payer, recent_blockhash, admin, dog, &unverified_foster, false).await;
+    assert!(result.is_err(), "Unverified fosters cannot take dogs");
+
+    assign_foster(banks_client, payer, recent_blockhash, admin, dog, &foster, false)
+        .await
+        .expect("Failed to assign foster");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.foster, Some(volunteer.pubkey()), "Foster mismatch");
+    let foster_account: Foster = fetch_account(banks_client, &foster).await;
+    assert_eq!(foster_account.dog_count, 1, "Foster should have one dog");
+    assert_eq!(foster_account.region, "Lisbon", "Region mismatch");
+
+    // Should fail because the foster is full
+    let result = assign_foster(banks_client, payer, recent_blockhash, admin, &buddy, &foster, false).await;
+    assert!(result.is_err(), "Fosters cannot exceed their capacity");
+
+    // Should fail because only the dog's admin or its foster can end the stay
+    let result = end_foster(banks_client, payer, recent_blockhash, &unverified, dog, &foster).await;
+    assert!(result.is_err(), "Strangers cannot end a foster stay");
+
+    end_foster(banks_client, payer, recent_blockhash, &volunteer, dog, &foster)
+        .await
+        .expect("Failed to end foster");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.foster, None, "Dog should have left the foster");
+    let foster_account: Foster = fetch_account(banks_client, &foster).await;
+    assert_eq!(foster_account.dog_count, 0, "Foster should have room again");
+
+    assign_foster(banks_client, payer, recent_blockhash, admin, &buddy, &foster, false)
+        .await
+        .expect("Failed to assign foster");
+
+    // Should fail because Rex no longer lives with this foster
+    let result = end_foster(banks_client, payer, recent_blockhash, admin, dog, &foster).await;
+    assert!(result.is_err(), "Only the current foster stay can be ended");
+}
+
+#[tokio::test]
+async fn test_foster_payouts() {
+    let (mut env, [volunteer]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    set_payee_policy(banks_client, payer, recent_blockhash, admin, true)
+        .await
+        .expect("Failed to set payee policy");
+    let foster = register_foster(banks_client, payer, recent_blockhash, admin, &volunteer, "Lisbon", 1, true)
+        .await
+        .expect("Failed to register foster");
+    assign_foster(banks_client, payer, recent_blockhash, admin, dog, &foster, true)
+        .await
+        .expect("Failed to assign foster");
+
+    let balance_before = banks_client.get_balance(volunteer.pubkey()).await.unwrap();
+    for category in [SpendingCategory::Food, SpendingCategory::Toys] {
+        withdraw_funds(
+            banks_client, payer, recent_blockhash, admin, shelter, Some(dog), &treasury.pubkey(), &volunteer.pubkey(),
+            100_000, category, [1; 32],
+        )
+        .await
+        .expect("Failed to pay foster");
+    }
+    let balance_after = banks_client.get_balance(volunteer.pubkey()).await.unwrap();
+    assert_eq!(balance_after - balance_before, 200_000, "Foster should receive the funds");
+
+    // Should fail because only food and toys funds go straight to fosters
+    let result = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, Some(dog), &treasury.pubkey(), &volunteer.pubkey(),
+        100_000, SpendingCategory::Medical, [1; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Medical funds must go to a registered payee");
+
+    // Should fail because the withdrawal is not linked to the fostered dog
+    let result = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, None, &treasury.pubkey(), &volunteer.pubkey(),
+        100_000, SpendingCategory::Food, [2; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Foster payouts must name the dog");
+
+    end_foster(banks_client, payer, recent_blockhash, admin, dog, &foster)
+        .await
+        .expect("Failed to end foster");
+
+    // Should fail because the dog no longer lives with the foster
+    let result = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, Some(dog), &treasury.pubkey(), &volunteer.pubkey(),
+        100_000, SpendingCategory::Food, [3; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Former fosters are not paid directly");
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use woofi::state::Donation;
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_hide_donation_message_by_curator() {
+    let (mut env, [donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let curator = Keypair::new();
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    let donation = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "Visit my spam site", 1,
+    )
+    .await
+    .expect("Failed to donate");
+
+    // Should fail before the wallet is granted curator rights
+    let result = hide_donation_message(banks_client, payer, recent_blockhash, &curator, false, &donation).await;
+    assert!(result.is_err(), "Only the admin or a curator can hide messages");
+
+    add_curator(banks_client, payer, recent_blockhash, admin, &curator.pubkey())
+        .await
+        .expect("Failed to add curator");
+
+    let result = hide_donation_message(banks_client, payer, recent_blockhash, &curator, true, &donation).await;
+    assert!(result.is_ok(), "Failed to hide message: {:?}", result);
+
+    let donation_account: Donation = fetch_account(banks_client, &donation).await;
+    assert!(donation_account.message_hidden, "Message should be flagged as hidden");
+    assert!(donation_account.message.is_empty(), "Message should be cleared");
+
+    // Removed curators lose the right to moderate
+    remove_curator(banks_client, payer, recent_blockhash, admin, &curator.pubkey())
+        .await
+        .expect("Failed to remove curator");
+    let result = hide_donation_message(banks_client, payer, recent_blockhash, &curator, true, &donation).await;
+    assert!(result.is_err(), "Removed curator should not hide messages");
+}
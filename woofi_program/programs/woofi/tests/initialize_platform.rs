@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use solana_program_test::*;
-use solana_sdk::{signature::Keypair, signer::Signer};
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer};
 use woofi::state::Platform;
 
 mod common;
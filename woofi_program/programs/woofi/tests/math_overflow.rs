@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use proptest::prelude::*;
+use woofi::state::Platform;
+
+fn platform(total_donations: u64, donation_count: u64, dog_count: u64) -> Platform {
+    let data = vec![0u8; 8 + std::mem::size_of::<Platform>()];
+    let mut platform = Platform::try_deserialize_unchecked(&mut data.as_slice()).unwrap();
+    platform.total_donations = total_donations;
+    platform.donation_count = donation_count;
+    platform.dog_count = dog_count;
+    platform
+}
+
+proptest! {
+    #[test]
+    fn record_donation_overflows_gracefully(
+        total in prop_oneof![Just(u64::MAX), Just(u64::MAX - 1), Just(0u64), any::<u64>()],
+        count in prop_oneof![Just(u64::MAX), Just(u64::MAX - 1), any::<u64>()],
+        amount in prop_oneof![Just(0u64), Just(1u64), Just(u64::MAX), any::<u64>()],
+    ) {
+        let mut platform = platform(total, count, 0);
+        let result = platform.record_donation(amount);
+
+        match (total.checked_add(amount), count.checked_add(1)) {
+            (Some(new_total), Some(new_count)) => {
+                prop_assert!(result.is_ok());
+                prop_assert_eq!(platform.total_donations, new_total);
+                prop_assert_eq!(platform.donation_count, new_count);
+            }
+            _ => {
+                prop_assert_eq!(result.unwrap_err(), woofi::error::WoofiError::MathOverflow.into());
+                prop_assert_eq!(platform.total_donations, total, "Stats must be untouched on overflow");
+                prop_assert_eq!(platform.donation_count, count, "Stats must be untouched on overflow");
+            }
+        }
+    }
+
+    #[test]
+    fn record_dog_overflows_gracefully(
+        dog_count in prop_oneof![Just(u64::MAX), Just(u64::MAX - 1), Just(u64::from(u32::MAX)), any::<u64>()],
+    ) {
+        let mut platform = platform(0, 0, dog_count);
+        let result = platform.record_dog();
+
+        match dog_count.checked_add(1) {
+            Some(new_count) => {
+                prop_assert!(result.is_ok());
+                prop_assert_eq!(platform.dog_count, new_count);
+            }
+            None => prop_assert!(result.is_err()),
+        }
+    }
+}
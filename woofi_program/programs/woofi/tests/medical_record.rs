@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use woofi::state::{Dog, DogProfile, MedicalRecord, SpendingCategory};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_medical_timeline() {
+    let (mut env, [curator, rescuer]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+    let clinic = Keypair::new().pubkey();
+    let clock: Clock = banks_client.get_sysvar().await.unwrap();
+
+    add_payee(banks_client, payer, recent_blockhash, admin, &clinic, "Downtown Vet Clinic")
+        .await
+        .expect("Failed to add payee");
+    add_curator(banks_client, payer, recent_blockhash, admin, &curator.pubkey())
+        .await
+        .expect("Failed to add curator");
+    let withdrawal = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, Some(dog), &treasury.pubkey(), &clinic,
+        2_000_000, SpendingCategory::Medical, [1; 32],
+    )
+    .await
+    .expect("Failed to withdraw");
+
+    let first = add_medical_record(
+        banks_client, payer, recent_blockhash, &curator, true, dog, &clinic, Some(&withdrawal),
+        clock.unix_timestamp - 86_400, "X-ray of the front leg", 2_000_000, [2; 32],
+    )
+    .await
+    .expect("Failed to add medical record");
+    let second = add_medical_record(
+        banks_client, payer, recent_blockhash, admin, false, dog, &clinic, None,
+        clock.unix_timestamp, "Rabies vaccination", 0, [3; 32],
+    )
+    .await
+    .expect("Failed to add medical record");
+
+    let record: MedicalRecord = fetch_account(banks_client, &first).await;
+    assert_eq!(record.index, 0, "Record index mismatch");
+    assert_eq!(record.clinic, clinic, "Clinic mismatch");
+    assert_eq!(record.withdrawal, Some(withdrawal), "Withdrawal should be linked");
+    assert_eq!(record.recorded_by, curator.pubkey(), "Recorder mismatch");
+
+    let record: MedicalRecord = fetch_account(banks_client, &second).await;
+    assert_eq!(record.index, 1, "Records should form a series");
+    assert_eq!(record.procedure, "Rabies vaccination", "Procedure mismatch");
+    assert_eq!(second, find_medical_record_pda(dog, 1).0, "Record address mismatch");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.medical_record_count, 2, "Record count mismatch");
+
+    // A partner shelter's admin keeps the timeline of their own dogs
+    let partner = register_shelter(banks_client, payer, recent_blockhash, admin, "Second Chance", &Keypair::new().pubkey(), &rescuer.pubkey())
+        .await
+        .expect("Failed to register partner shelter");
+    add_dog(
+        banks_client, payer, recent_blockhash, &rescuer, &partner,
+        "Bella", 0, 0, "https://example.com/bella.png", "Found in the park", DogProfile::default(), "", [0; 32],
+    )
+    .await
+    .expect("Failed to add dog");
+    let bella = find_dog_pda(&partner, "Bella").0;
+    let third = add_medical_record(
+        banks_client, payer, recent_blockhash, &rescuer, false, &bella, &clinic, None,
+        clock.unix_timestamp, "Deworming", 0, [4; 32],
+    )
+    .await
+    .expect("Failed to add medical record as the dog's admin");
+    let record: MedicalRecord = fetch_account(banks_client, &third).await;
+    assert_eq!(record.recorded_by, rescuer.pubkey(), "Recorder mismatch");
+
+    // Should fail because the shelter admin does not administer the other shelter's dog
+    let result = add_medical_record(
+        banks_client, payer, recent_blockhash, &rescuer, false, dog, &clinic, None,
+        clock.unix_timestamp, "Checkup", 0, [5; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Shelter admins can only record treatments of their own dogs");
+}
+
+#[tokio::test]
+async fn test_invalid_medical_records() {
+    let (mut env, [stranger]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+    let clinic = Keypair::new().pubkey();
+    let other_clinic = Keypair::new().pubkey();
+    let clock: Clock = banks_client.get_sysvar().await.unwrap();
+
+    add_payee(banks_client, payer, recent_blockhash, admin, &clinic, "Downtown Vet Clinic")
+        .await
+        .expect("Failed to add payee");
+    add_payee(banks_client, payer, recent_blockhash, admin, &other_clinic, "Uptown Vet Clinic")
+        .await
+        .expect("Failed to add payee");
+
+    // Should fail because the signer is neither the admin nor a curator
+    let result = add_medical_record(
+        banks_client, payer, recent_blockhash, &stranger, false, dog, &clinic, None,
+        clock.unix_timestamp, "Checkup", 0, [1; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Only curators can add medical records");
+
+    // Should fail because the clinic is not a registered payee
+    let result = add_medical_record(
+        banks_client, payer, recent_blockhash, admin, false, dog, &Keypair::new().pubkey(), None,
+        clock.unix_timestamp, "Checkup", 0, [1; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Clinics must be registered payees");
+
+    // Should fail because the withdrawal paid another clinic
+    let withdrawal = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, Some(dog), &treasury.pubkey(), &other_clinic,
+        1_000_000, SpendingCategory::Medical, [1; 32],
+    )
+    .await
+    .expect("Failed to withdraw");
+    let result = add_medical_record(
+        banks_client, payer, recent_blockhash, admin, false, dog, &clinic, Some(&withdrawal),
+        clock.unix_timestamp, "Checkup", 1_000_000, [1; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Linked withdrawals must pay the clinic");
+
+    // Should fail because the withdrawal paid the clinic for food
+    let withdrawal = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, Some(dog), &treasury.pubkey(), &clinic,
+        1_000_000, SpendingCategory::Food, [2; 32],
+    )
+    .await
+    .expect("Failed to withdraw");
+    let result = add_medical_record(
+        banks_client, payer, recent_blockhash, admin, false, dog, &clinic, Some(&withdrawal),
+        clock.unix_timestamp, "Checkup", 1_000_000, [1; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Linked withdrawals must be medical payments");
+
+    // Should fail because the treatment is in the future
+    let result = add_medical_record(
+        banks_client, payer, recent_blockhash, admin, false, dog, &clinic, None,
+        clock.unix_timestamp + 86_400, "Checkup", 0, [1; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Future treatments should be rejected");
+}
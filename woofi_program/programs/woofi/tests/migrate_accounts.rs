@@ -0,0 +1,294 @@
+use anchor_lang::{prelude::*, Discriminator};
+use solana_program_test::*;
+use solana_sdk::{account::Account, rent::Rent, signature::Keypair, signer::Signer};
+use woofi::state::{Dog, DogLifecycle, DogProfile, DogV0, Donation, DonationV0, Platform, PlatformV0, Shelter, SpendingCategory};
+
+mod common;
+use common::*;
+
+// Account fixture written in an older layout and allocated with `len` bytes
+fn v0_account<T: AnchorSerialize>(discriminator: &[u8], v0: &T, len: usize) -> Account {
+    let mut data = discriminator.to_vec();
+    v0.serialize(&mut data).unwrap();
+    assert!(data.len() <= len, "Fixture does not fit the allocated size");
+    data.resize(len, 0);
+
+    Account {
+        lamports: Rent::default().minimum_balance(len),
+        data,
+        owner: program_id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn platform_v0(admin: &Pubkey) -> PlatformV0 {
+    PlatformV0 {
+        admin: *admin,
+        treasury: Pubkey::new_unique(),
+        total_donations: 5_000_000_000,
+        dog_count: 3,
+        donation_count: 42,
+    }
+}
+
+fn dog_v0() -> DogV0 {
+    DogV0 {
+        name: "Rex".to_string(),
+        age: 3,
+        image_url: "https://example.com/rex.png".to_string(),
+        story: "Found on the street".to_string(),
+        needs_food: true,
+        needs_toys: false,
+        needs_medical: true,
+        needs_shelter: false,
+        needs_other: String::new(),
+        total_donations: 1_000_000,
+        admin: Pubkey::new_unique(),
+        active: true,
+    }
+}
+
+fn donation_v0(dog: &Pubkey) -> DonationV0 {
+    DonationV0 {
+        donor: Pubkey::new_unique(),
+        dog_id: *dog,
+        amount: 1_000_000,
+        timestamp: 1,
+        message: "Good boy".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_migrate_v0_accounts() {
+    let admin = Keypair::new();
+    let dog = Pubkey::new_unique();
+    let donation = Pubkey::new_unique();
+
+    // Accounts as allocated by the original add_dog and donate
+    let mut program_test = setup_program_test();
+    program_test.add_account(
+        find_platform_pda().0,
+        v0_account(Platform::DISCRIMINATOR, &platform_v0(&admin.pubkey()), Platform::V0_LEN),
+    );
+    program_test.add_account(dog, v0_account(Dog::DISCRIMINATOR, &dog_v0(), Dog::V0_LEN));
+    program_test.add_account(donation, v0_account(Donation::DISCRIMINATOR, &donation_v0(&dog), Donation::V0_LEN));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    airdrop(&mut banks_client, &payer, &recent_blockhash, &admin.pubkey(), 10_000_000_000).await;
+
+    migrate_platform(&mut banks_client, &payer, &recent_blockhash, &admin)
+        .await
+        .expect("Failed to migrate platform");
+    let platform: Platform = fetch_account(&mut banks_client, &find_platform_pda().0).await;
+    assert_eq!(platform.version, Platform::VERSION, "Platform version mismatch");
+    assert_eq!(platform.admin, admin.pubkey(), "Admin mismatch");
+    assert_eq!(platform.donation_count, 42, "Donation count mismatch");
+    assert_eq!(platform.dog_count, 3, "Dog count mismatch");
+    assert!(!platform.receipts_enabled, "Receipts should start disabled");
+
+    migrate_dog(&mut banks_client, &payer, &recent_blockhash, &dog)
+        .await
+        .expect("Failed to migrate dog");
+    let dog_account: Dog = fetch_account(&mut banks_client, &dog).await;
+    assert_eq!(dog_account.version, Dog::VERSION, "Dog version mismatch");
+    assert_eq!(dog_account.name, "Rex", "Dog name mismatch");
+    assert_eq!(dog_account.owner_shelter, Pubkey::default(), "Dogs from before shelters are not linked to one");
+    assert_eq!(dog_account.total_donations, 1_000_000, "Dog donations mismatch");
+    assert!(dog_account.needs_food && dog_account.needs_medical, "Needs flags should be preserved");
+    let needs: Vec<_> = dog_account.needs.iter().map(|need| (need.category, need.target, need.fulfilled)).collect();
+    assert_eq!(
+        needs,
+        [(SpendingCategory::Food, 0, false), (SpendingCategory::Medical, 0, false)],
+        "Flags should become open needs",
+    );
+    assert_eq!(dog_account.birth_date, 0, "Birth date should be unknown");
+    assert_eq!(dog_account.age_years(0), Some(3), "Age should fall back to the age at intake");
+    assert_eq!(dog_account.profile, DogProfile::default(), "Profile should start empty");
+    assert_eq!(dog_account.medical_record_count, 0, "Medical records should start empty");
+    assert_eq!(dog_account.update_count, 0, "Updates should start empty");
+    assert_eq!(dog_account.lifecycle, DogLifecycle::Available, "Migrated dogs should be available");
+    assert_eq!(dog_account.foster, None, "Migrated dogs should not be fostered");
+
+    migrate_donation(&mut banks_client, &payer, &recent_blockhash, &donation)
+        .await
+        .expect("Failed to migrate donation");
+    let donation_account: Donation = fetch_account(&mut banks_client, &donation).await;
+    assert_eq!(donation_account.version, Donation::VERSION, "Donation version mismatch");
+    assert_eq!(donation_account.dog_id, dog, "Donation dog mismatch");
+    assert_eq!(donation_account.message, "Good boy", "Donation message mismatch");
+    assert_eq!(donation_account.need_index, None, "Old donations are not earmarked");
+    assert_eq!(donation_account.created_at, 0, "Old donations have no cluster timestamp");
+    assert!(!donation_account.anonymous, "Old donations are public");
+
+    let dog_info = banks_client.get_account(dog).await.unwrap().unwrap();
+    assert_eq!(dog_info.data.len(), Dog::LEN, "Dog account should be grown");
+    let donation_info = banks_client.get_account(donation).await.unwrap().unwrap();
+    assert_eq!(donation_info.data.len(), Donation::LEN, "Donation account should be grown");
+
+    // Should fail because the accounts are already at the current version
+    let result = migrate_dog(&mut banks_client, &payer, &recent_blockhash, &dog).await;
+    assert!(result.is_err(), "A migrated dog cannot be migrated again");
+    let result = migrate_donation(&mut banks_client, &payer, &recent_blockhash, &donation).await;
+    assert!(result.is_err(), "A migrated donation cannot be migrated again");
+}
+
+#[tokio::test]
+async fn test_new_accounts_are_current() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, dog, .. } = &mut env;
+
+    let platform: Platform = fetch_account(banks_client, &find_platform_pda().0).await;
+    assert_eq!(platform.version, Platform::VERSION, "New platforms use the current version");
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.version, Dog::VERSION, "New dogs use the current version");
+
+    // Should fail because there is nothing to migrate
+    let result = migrate_platform(banks_client, payer, recent_blockhash, admin).await;
+    assert!(result.is_err(), "Current accounts cannot be migrated");
+}
+
+#[tokio::test]
+async fn test_assign_legacy_dog() {
+    let admin = Keypair::new();
+    let legacy_treasury = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+    let dog = Pubkey::new_unique();
+
+    // The original donate paid every donation into the platform treasury
+    let mut program_test = setup_program_test();
+    let platform = PlatformV0 { treasury: legacy_treasury, ..platform_v0(&admin.pubkey()) };
+    program_test.add_account(find_platform_pda().0, v0_account(Platform::DISCRIMINATOR, &platform, Platform::V0_LEN));
+    program_test.add_account(dog, v0_account(Dog::DISCRIMINATOR, &dog_v0(), Dog::V0_LEN));
+    for wallet in [legacy_treasury, treasury] {
+        program_test.add_account(
+            wallet,
+            Account { lamports: 1_000_000_000, data: vec![], owner: program_id(), executable: false, rent_epoch: 0 },
+        );
+    }
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    airdrop(&mut banks_client, &payer, &recent_blockhash, &admin.pubkey(), 10_000_000_000).await;
+
+    migrate_platform(&mut banks_client, &payer, &recent_blockhash, &admin)
+        .await
+        .expect("Failed to migrate platform");
+    migrate_dog(&mut banks_client, &payer, &recent_blockhash, &dog)
+        .await
+        .expect("Failed to migrate dog");
+    let shelter = register_shelter(&mut banks_client, &payer, &recent_blockhash, &admin, "Happy Paws", &treasury, &admin.pubkey())
+        .await
+        .expect("Failed to register shelter");
+
+    // Should fail because only the platform admin can assign legacy dogs
+    let stranger = Keypair::new();
+    airdrop(&mut banks_client, &payer, &recent_blockhash, &stranger.pubkey(), 1_000_000_000).await;
+    let result = assign_legacy_dog(&mut banks_client, &payer, &recent_blockhash, &stranger, &shelter, &dog).await;
+    assert!(result.is_err(), "Only the platform admin can assign legacy dogs");
+
+    assign_legacy_dog(&mut banks_client, &payer, &recent_blockhash, &admin, &shelter, &dog)
+        .await
+        .expect("Failed to assign legacy dog");
+
+    let dog_account: Dog = fetch_account(&mut banks_client, &dog).await;
+    assert_eq!(dog_account.owner_shelter, shelter, "Dog should belong to the shelter");
+    assert_eq!(dog_account.admin, admin.pubkey(), "Dog should be administered by the shelter admin");
+    let shelter_account: Shelter = fetch_account(&mut banks_client, &shelter).await;
+    assert_eq!(shelter_account.dog_count, 1, "Shelter dog count mismatch");
+    assert_eq!(shelter_account.total_donations, 1_000_000, "Dog balance should be booked to the shelter");
+    let platform: Platform = fetch_account(&mut banks_client, &find_platform_pda().0).await;
+    assert_eq!(platform.total_donations, 5_000_000_000, "Platform totals already count the dog's donations");
+
+    // The dog's balance left the platform treasury for the shelter's
+    assert_eq!(banks_client.get_balance(legacy_treasury).await.unwrap(), 999_000_000, "Legacy treasury balance mismatch");
+    let reconciliation = reconcile(&mut banks_client, &payer, &recent_blockhash, &shelter, &treasury).await;
+    assert_eq!(reconciliation.actual, 1_001_000_000, "Shelter treasury balance mismatch");
+    assert_eq!(reconciliation.discrepancy, 0, "Shelter should reconcile");
+
+    // The dog now takes donations through its shelter
+    let donor = Keypair::new();
+    airdrop(&mut banks_client, &payer, &recent_blockhash, &donor.pubkey(), 1_000_000_000).await;
+    donate(&mut banks_client, &payer, &recent_blockhash, &donor, &shelter, &dog, &treasury, 1_000_000, "", 1)
+        .await
+        .expect("Failed to donate to the assigned dog");
+
+    // Should fail because the dog already belongs to a shelter
+    let result = assign_legacy_dog(&mut banks_client, &payer, &recent_blockhash, &admin, &shelter, &dog).await;
+    assert!(result.is_err(), "Assigned dogs cannot be assigned again");
+}
+
+#[tokio::test]
+async fn test_update_migrated_dog_keeps_age() {
+    let admin = Keypair::new();
+    let dog = Pubkey::new_unique();
+
+    let mut program_test = setup_program_test();
+    program_test.add_account(
+        find_platform_pda().0,
+        v0_account(Platform::DISCRIMINATOR, &platform_v0(&admin.pubkey()), Platform::V0_LEN),
+    );
+    let legacy_dog = DogV0 { admin: admin.pubkey(), ..dog_v0() };
+    program_test.add_account(dog, v0_account(Dog::DISCRIMINATOR, &legacy_dog, Dog::V0_LEN));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    airdrop(&mut banks_client, &payer, &recent_blockhash, &admin.pubkey(), 10_000_000_000).await;
+
+    migrate_platform(&mut banks_client, &payer, &recent_blockhash, &admin)
+        .await
+        .expect("Failed to migrate platform");
+    migrate_dog(&mut banks_client, &payer, &recent_blockhash, &dog)
+        .await
+        .expect("Failed to migrate dog");
+
+    // Old dogs have no intake date, which an edit has to supply
+    update_dog(&mut banks_client, &payer, &recent_blockhash, &admin, &dog, |dog| {
+        dog.intake_date = 1;
+        dog.story = "Adopted a favourite blanket".to_string();
+    })
+    .await
+    .expect("Failed to update migrated dog");
+
+    let dog_account: Dog = fetch_account(&mut banks_client, &dog).await;
+    assert_eq!(dog_account.story, "Adopted a favourite blanket", "Story mismatch");
+    assert_eq!(dog_account.birth_date, 0, "Birth date should stay unknown");
+    assert_eq!(dog_account.age_years(0), Some(3), "Age should survive the edit");
+}
+
+#[tokio::test]
+async fn test_migrated_needs_stay_open() {
+    let admin = Keypair::new();
+    let dog = Pubkey::new_unique();
+
+    let mut program_test = setup_program_test();
+    program_test.add_account(
+        find_platform_pda().0,
+        v0_account(Platform::DISCRIMINATOR, &platform_v0(&admin.pubkey()), Platform::V0_LEN),
+    );
+    let legacy_dog = DogV0 { admin: admin.pubkey(), needs_other: "Leash".to_string(), ..dog_v0() };
+    program_test.add_account(dog, v0_account(Dog::DISCRIMINATOR, &legacy_dog, Dog::V0_LEN));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    airdrop(&mut banks_client, &payer, &recent_blockhash, &admin.pubkey(), 10_000_000_000).await;
+
+    migrate_platform(&mut banks_client, &payer, &recent_blockhash, &admin)
+        .await
+        .expect("Failed to migrate platform");
+    migrate_dog(&mut banks_client, &payer, &recent_blockhash, &dog)
+        .await
+        .expect("Failed to migrate dog");
+
+    // Adding a need re-derives the flags, which must keep the carried-over needs
+    add_need(&mut banks_client, &payer, &recent_blockhash, &admin, &dog, SpendingCategory::Toys, "Chew toy", 100_000)
+        .await
+        .expect("Failed to add need");
+    let dog_account: Dog = fetch_account(&mut banks_client, &dog).await;
+    assert_eq!(dog_account.needs.len(), 4, "Needs count mismatch");
+    assert!(dog_account.needs_food, "Food should still be needed");
+    assert!(dog_account.needs_medical, "Medical care should still be needed");
+    assert!(dog_account.needs_toys, "Toys should be needed");
+    assert_eq!(dog_account.needs_other, "Leash", "Other need mismatch");
+
+    // The shelter closes carried-over needs once they are met
+    close_need(&mut banks_client, &payer, &recent_blockhash, &admin, &dog, 0)
+        .await
+        .expect("Failed to close need");
+    let dog_account: Dog = fetch_account(&mut banks_client, &dog).await;
+    assert!(!dog_account.needs_food, "Food should no longer be needed");
+    assert!(dog_account.needs_medical, "Medical care should still be needed");
+}
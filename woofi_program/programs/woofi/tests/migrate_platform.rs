@@ -0,0 +1,65 @@
+use anchor_lang::{prelude::*, Discriminator};
+use solana_program_test::*;
+use solana_sdk::{account::Account, rent::Rent, signature::Keypair, signer::Signer};
+use woofi::state::{Platform, PlatformV0};
+
+mod common;
+use common::*;
+
+// Platform account as written by the original `initialize_platform`, with u32 counters
+fn v0_platform_account(admin: &Pubkey) -> Account {
+    let v0 = PlatformV0 {
+        admin: *admin,
+        treasury: Pubkey::new_unique(),
+        total_donations: 5_000_000_000,
+        dog_count: u32::MAX,
+        donation_count: 42,
+    };
+
+    let mut data = Platform::DISCRIMINATOR.to_vec();
+    v0.serialize(&mut data).unwrap();
+    assert_eq!(data.len(), Platform::V0_LEN, "Fixture should match the deployed size");
+
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: program_id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[tokio::test]
+async fn test_migrate_platform() {
+    let admin = Keypair::new();
+    let mut program_test = setup_program_test();
+    program_test.add_account(find_platform_pda().0, v0_platform_account(&admin.pubkey()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    airdrop(&mut banks_client, &payer, &recent_blockhash, &admin.pubkey(), 10_000_000_000).await;
+
+    // Should fail because only the admin can migrate
+    let stranger = Keypair::new();
+    airdrop(&mut banks_client, &payer, &recent_blockhash, &stranger.pubkey(), 10_000_000_000).await;
+    let result = migrate_platform(&mut banks_client, &payer, &recent_blockhash, &stranger).await;
+    assert!(result.is_err(), "Only the admin can migrate the platform");
+
+    let result = migrate_platform(&mut banks_client, &payer, &recent_blockhash, &admin).await;
+    assert!(result.is_ok(), "Failed to migrate platform: {:?}", result);
+
+    let platform: Platform = fetch_account(&mut banks_client, &find_platform_pda().0).await;
+    assert_eq!(platform.admin, admin.pubkey(), "Admin mismatch");
+    assert_eq!(platform.total_donations, 5_000_000_000, "Total donations mismatch");
+    assert_eq!(platform.dog_count, u64::from(u32::MAX), "Dog count mismatch");
+    assert_eq!(platform.donation_count, 42, "Donation count mismatch");
+    assert_eq!(platform.version, Platform::VERSION, "Platform version mismatch");
+    assert_eq!(platform.shelter_count, 0, "Shelters did not exist before the version header");
+    assert_eq!(platform.paused, 0, "Migrated platforms should not be paused");
+    assert_eq!(platform.guardian, Pubkey::default(), "Guardian should be unset");
+
+    let platform_info = banks_client.get_account(find_platform_pda().0).await.unwrap().unwrap();
+    assert_eq!(platform_info.data.len(), 8 + Platform::INIT_SPACE, "Platform account should be grown");
+
+    // Should fail because the account was already migrated
+    let result = migrate_platform(&mut banks_client, &payer, &recent_blockhash, &admin).await;
+    assert!(result.is_err(), "A migrated platform cannot be migrated again");
+}
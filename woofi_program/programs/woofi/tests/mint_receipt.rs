@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use mpl_token_metadata::accounts::Metadata;
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+use woofi::state::Donation;
+
+mod common;
+use common::*;
+
+// Receipt minting CPIs into the Metaplex Token Metadata program, so the test loads
+// its binary from `tests/fixtures/mpl_token_metadata.so`. The binary is not committed;
+// see "Program Tests" in the README for the command that dumps it from mainnet.
+fn setup_receipt_program_test() -> ProgramTest {
+    let mut program_test = setup_program_test();
+    program_test.prefer_bpf(true);
+    program_test.add_program("mpl_token_metadata", mpl_token_metadata::ID, None);
+    program_test
+}
+
+#[tokio::test]
+async fn test_mint_receipt() {
+    let (mut env, [donor]) = start_with_wallets(setup_receipt_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    set_receipt_config(banks_client, payer, recent_blockhash, admin, true, 1_000_000)
+        .await
+        .expect("Failed to enable receipts");
+
+    let donation_pda = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000_000, "Good boy", 1,
+    )
+    .await
+    .expect("Failed to donate");
+
+    let result = mint_receipt(
+        banks_client, payer, recent_blockhash, &donor, dog, &donation_pda,
+        "https://example.com/receipts/1.json",
+    )
+    .await;
+    assert!(result.is_ok(), "Failed to mint receipt: {:?}", result);
+
+    // The donation record points at the receipt mint
+    let (mint_pda, _) = find_receipt_mint_pda(&donation_pda);
+    let donation: Donation = fetch_account(banks_client, &donation_pda).await;
+    assert_eq!(donation.receipt_mint, mint_pda, "Receipt mint mismatch");
+
+    // The donor holds exactly one receipt token
+    let (receipt_token_pda, _) = find_receipt_token_pda(&donation_pda);
+    let token_account = banks_client.get_account(receipt_token_pda).await.unwrap().unwrap();
+    let token_account = TokenAccount::try_deserialize(&mut token_account.data.as_ref()).unwrap();
+    assert_eq!(token_account.owner, donor.pubkey(), "Receipt owner mismatch");
+    assert_eq!(token_account.amount, 1, "Receipt amount should be 1");
+
+    // The Metaplex metadata account names the amount and the dog
+    let (metadata_pda, _) = find_metadata_pda(&mint_pda);
+    let metadata_account = banks_client.get_account(metadata_pda).await.unwrap().expect("Metadata not found");
+    let metadata = Metadata::from_bytes(&metadata_account.data).unwrap();
+    assert_eq!(metadata.name.trim_end_matches('\0'), "Woofi 1 SOL: Rex", "Receipt name mismatch");
+
+    // A second receipt for the same donation is rejected
+    let result = mint_receipt(
+        banks_client, payer, recent_blockhash, &donor, dog, &donation_pda,
+        "https://example.com/receipts/1.json",
+    )
+    .await;
+    assert!(result.is_err(), "Should not mint a second receipt");
+}
+
+#[tokio::test]
+async fn test_mint_receipt_below_minimum() {
+    let (mut env, [donor]) = start_with_wallets(setup_receipt_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    set_receipt_config(banks_client, payer, recent_blockhash, admin, true, 1_000_000_000)
+        .await
+        .expect("Failed to enable receipts");
+
+    let donation_pda = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000, "", 1,
+    )
+    .await
+    .expect("Failed to donate");
+
+    // Should fail because the donation is below the receipt minimum
+    let result = mint_receipt(
+        banks_client, payer, recent_blockhash, &donor, dog, &donation_pda,
+        "https://example.com/receipts/1.json",
+    )
+    .await;
+    assert!(result.is_err(), "Should reject donations below the receipt minimum");
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use woofi::state::{Payee, SpendingCategory};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_withdrawals_to_registered_payees() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, .. } = &mut env;
+    // Recipients start empty, so every payment has to cover their rent exemption
+    let clinic = Keypair::new().pubkey();
+    let stranger = Keypair::new().pubkey();
+
+    add_payee(banks_client, payer, recent_blockhash, admin, &clinic, "Downtown Vet Clinic")
+        .await
+        .expect("Failed to add payee");
+    let payee: Payee = fetch_account(banks_client, &find_payee_pda(&clinic).0).await;
+    assert_eq!(payee.label, "Downtown Vet Clinic", "Payee label mismatch");
+
+    set_payee_policy(banks_client, payer, recent_blockhash, admin, true)
+        .await
+        .expect("Failed to set payee policy");
+
+    let result = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, None, &treasury.pubkey(), &clinic,
+        1_000_000, SpendingCategory::Medical, [0; 32],
+    )
+    .await;
+    assert!(result.is_ok(), "Failed to withdraw to payee: {:?}", result);
+
+    // Should fail because the recipient is not registered
+    let result = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, None, &treasury.pubkey(), &stranger,
+        1_000_000, SpendingCategory::Other, [0; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Withdrawals to unregistered recipients should be rejected");
+
+    // Should fail once the payee is removed
+    remove_payee(banks_client, payer, recent_blockhash, admin, &clinic)
+        .await
+        .expect("Failed to remove payee");
+    let result = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, None, &treasury.pubkey(), &clinic,
+        1_000_000, SpendingCategory::Medical, [0; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Removed payees should be rejected");
+
+    // Without the policy any recipient is allowed
+    set_payee_policy(banks_client, payer, recent_blockhash, admin, false)
+        .await
+        .expect("Failed to set payee policy");
+    let result = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, None, &treasury.pubkey(), &stranger,
+        1_000_000, SpendingCategory::Other, [0; 32],
+    )
+    .await;
+    assert!(result.is_ok(), "Any recipient is allowed without the policy: {:?}", result);
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use woofi::state::{Platform, Shelter, SpendingCategory};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_reconcile_treasury() {
+    let (mut env, [donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    let opening_balance = banks_client.get_balance(treasury.pubkey()).await.unwrap();
+
+    donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        5_000_000, "", 1,
+    )
+    .await
+    .expect("Failed to donate");
+    withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, None, &treasury.pubkey(), &Keypair::new().pubkey(),
+        2_000_000, SpendingCategory::Food, [0; 32],
+    )
+    .await
+    .expect("Failed to withdraw");
+
+    let shelter_account: Shelter = fetch_account(banks_client, shelter).await;
+    assert_eq!(shelter_account.total_withdrawn, 2_000_000, "Shelter withdrawals mismatch");
+    assert_eq!(shelter_account.opening_balance, opening_balance, "Opening balance mismatch");
+    let platform: Platform = fetch_account(banks_client, &find_platform_pda().0).await;
+    assert_eq!(platform.total_withdrawn, 2_000_000, "Platform withdrawals mismatch");
+    assert_eq!(platform.total_fees, 0, "No fees are charged");
+
+    // The treasury's opening balance is expected on top of the booked funds
+    let reconciliation = reconcile(banks_client, payer, recent_blockhash, shelter, &treasury.pubkey()).await;
+    assert_eq!(reconciliation.booked, opening_balance + 3_000_000, "Booked balance mismatch");
+    assert_eq!(reconciliation.actual, opening_balance + 3_000_000, "Actual balance mismatch");
+    assert_eq!(reconciliation.discrepancy, 0, "Discrepancy mismatch");
+}
+
+#[tokio::test]
+async fn test_reconcile_after_treasury_update() {
+    let (mut env, [donor, new_treasury]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        5_000_000, "", 1,
+    )
+    .await
+    .expect("Failed to donate");
+
+    // Funds booked to the previous treasury stay there
+    let opening_balance = banks_client.get_balance(new_treasury.pubkey()).await.unwrap();
+    update_treasury(banks_client, payer, recent_blockhash, admin, shelter, &new_treasury.pubkey())
+        .await
+        .expect("Failed to update treasury");
+    let reconciliation = reconcile(banks_client, payer, recent_blockhash, shelter, &new_treasury.pubkey()).await;
+    assert_eq!(reconciliation.booked, opening_balance, "Booked balance mismatch");
+    assert_eq!(reconciliation.discrepancy, 0, "Discrepancy mismatch");
+
+    donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &new_treasury.pubkey(),
+        1_000_000, "", 2,
+    )
+    .await
+    .expect("Failed to donate");
+    let reconciliation = reconcile(banks_client, payer, recent_blockhash, shelter, &new_treasury.pubkey()).await;
+    assert_eq!(reconciliation.booked, opening_balance + 1_000_000, "Booked balance mismatch");
+    assert_eq!(reconciliation.actual, opening_balance + 1_000_000, "Actual balance mismatch");
+    assert_eq!(reconciliation.discrepancy, 0, "Discrepancy mismatch");
+}
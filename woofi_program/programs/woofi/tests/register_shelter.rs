@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer, system_program};
+use woofi::{
+    state::{Dog, Platform, Shelter},
+    MAX_SHELTER_NAME_LEN,
+};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_register_shelter() {
+    let (mut env, [donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    let shelter_account: Shelter = fetch_account(banks_client, shelter).await;
+    assert_eq!(shelter_account.name, "Happy Paws", "Shelter name mismatch");
+    assert_eq!(shelter_account.admin, admin.pubkey(), "Shelter admin mismatch");
+    assert_eq!(shelter_account.treasury, treasury.pubkey(), "Shelter treasury mismatch");
+    assert_eq!(shelter_account.dog_count, 1, "Shelter should own one dog");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.owner_shelter, *shelter, "Dog should belong to the shelter");
+
+    donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "", 1,
+    )
+    .await
+    .expect("Failed to donate");
+
+    let shelter_account: Shelter = fetch_account(banks_client, shelter).await;
+    assert_eq!(shelter_account.total_donations, 1_000_000, "Shelter total mismatch");
+    assert_eq!(shelter_account.donation_count, 1, "Shelter donation count mismatch");
+
+    let platform: Platform = fetch_account(banks_client, &find_platform_pda().0).await;
+    assert_eq!(platform.shelter_count, 1, "Platform shelter count mismatch");
+
+    // Should fail because the donation is routed to another shelter's treasury
+    let other_treasury = Keypair::new();
+    let other_shelter = register_shelter(banks_client, payer, recent_blockhash, admin, "Second Chance", &other_treasury.pubkey(), &admin.pubkey())
+        .await
+        .expect("Failed to register second shelter");
+    let result = donate(
+        banks_client, payer, recent_blockhash, &donor, &other_shelter, dog, &other_treasury.pubkey(),
+        1_000_000, "", 2,
+    )
+    .await;
+    assert!(result.is_err(), "Donations must go to the dog's own shelter");
+}
+
+#[tokio::test]
+async fn test_register_shelter_unauthorized() {
+    let (mut env, [stranger]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, .. } = &mut env;
+
+    // Should fail because only the platform admin can register shelters
+    let result = register_shelter(banks_client, payer, recent_blockhash, &stranger, "Impostor", &Keypair::new().pubkey(), &stranger.pubkey()).await;
+    assert!(result.is_err(), "Only the platform admin can register shelters");
+
+    // The platform admin onboards partner rescues on their behalf
+    let partner = register_shelter(banks_client, payer, recent_blockhash, admin, "Second Chance", &Keypair::new().pubkey(), &stranger.pubkey())
+        .await
+        .expect("Failed to register partner shelter");
+    let shelter_account: Shelter = fetch_account(banks_client, &partner).await;
+    assert_eq!(shelter_account.admin, stranger.pubkey(), "Partner should administer their shelter");
+
+    // Should fail because the name does not fit in a PDA seed. No PDA can be derived
+    // from it, so the instruction is built by hand with a placeholder shelter.
+    let treasury = Keypair::new().pubkey();
+    let register_shelter_ix = Instruction {
+        program_id: program_id(),
+        accounts: woofi::accounts::RegisterShelter {
+            admin: admin.pubkey(),
+            platform: find_platform_pda().0,
+            shelter: Pubkey::new_unique(),
+            shelter_treasury: treasury,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: woofi::instruction::RegisterShelter {
+            name: "a".repeat(MAX_SHELTER_NAME_LEN + 1),
+            treasury,
+            shelter_admin: stranger.pubkey(),
+        }
+        .data(),
+    };
+    let result = process_instruction(banks_client, payer, recent_blockhash, register_shelter_ix, &[admin]).await;
+    assert!(result.is_err(), "Shelter names are limited to 32 bytes");
+}
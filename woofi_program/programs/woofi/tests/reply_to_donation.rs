@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+use woofi::state::Donation;
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_reply_to_donation() {
+    let (mut env, [donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    let donation = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "Good boy", 1,
+    )
+    .await
+    .expect("Failed to donate");
+
+    let result = reply_to_donation(banks_client, payer, recent_blockhash, admin, false, &donation, "Thank you!").await;
+    assert!(result.is_ok(), "Failed to reply: {:?}", result);
+
+    let donation_account: Donation = fetch_account(banks_client, &donation).await;
+    assert_eq!(donation_account.thank_you, "Thank you!", "Reply mismatch");
+
+    // Should fail because the donor is not a curator
+    let result = reply_to_donation(banks_client, payer, recent_blockhash, &donor, false, &donation, "Thanks me").await;
+    assert!(result.is_err(), "Only the admin or a curator can reply");
+}
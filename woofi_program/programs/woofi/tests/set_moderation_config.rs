@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+use woofi::state::ModerationConfig;
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_set_moderation_config() {
+    let (mut env, [donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    let result = set_moderation_config(banks_client, payer, recent_blockhash, admin, 1, &["casino"]).await;
+    assert!(result.is_ok(), "Failed to set moderation config: {:?}", result);
+
+    let moderation: ModerationConfig = fetch_account(banks_client, &find_moderation_pda().0).await;
+    assert_eq!(moderation.max_urls, 1, "Max URLs mismatch");
+    assert_eq!(moderation.banned_substrings, vec!["casino".to_string()], "Banned substrings mismatch");
+
+    // Should fail because the message contains a banned substring
+    let result = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "Best CASINO bonus", 1,
+    )
+    .await;
+    assert!(result.is_err(), "Banned content should be rejected");
+
+    // Should fail because the message contains too many links
+    let result = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "https://a.example www.b.example", 2,
+    )
+    .await;
+    assert!(result.is_err(), "Too many links should be rejected");
+
+    let result = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "Photos at https://rescue.example", 3,
+    )
+    .await;
+    assert!(result.is_ok(), "Allowed message should be accepted: {:?}", result);
+
+    // Should fail because only the admin can change the rules
+    let result = set_moderation_config(banks_client, payer, recent_blockhash, &donor, 10, &[]).await;
+    assert!(result.is_err(), "Only the admin can set moderation rules");
+}
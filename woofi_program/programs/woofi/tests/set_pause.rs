@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use woofi::{state::{DogProfile, Donation, Platform}, PAUSE_ALL, PAUSE_DOG_MANAGEMENT, PAUSE_DONATIONS, PAUSE_WITHDRAWALS};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_guardian_pauses_donations() {
+    let (mut env, [donor, guardian]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    // Should fail before the guardian is appointed
+    let result = set_pause(banks_client, payer, recent_blockhash, &guardian, PAUSE_DONATIONS).await;
+    assert!(result.is_err(), "Only the admin or guardian can pause");
+
+    set_guardian(banks_client, payer, recent_blockhash, admin, &guardian.pubkey())
+        .await
+        .expect("Failed to set guardian");
+
+    let result = set_pause(banks_client, payer, recent_blockhash, &guardian, PAUSE_DONATIONS).await;
+    assert!(result.is_ok(), "Failed to pause donations: {:?}", result);
+
+    let platform: Platform = fetch_account(banks_client, &find_platform_pda().0).await;
+    assert_eq!(platform.paused, PAUSE_DONATIONS, "Pause flags mismatch");
+
+    // Should fail because donations are paused
+    let result = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "", 1,
+    )
+    .await;
+    assert!(result.is_err(), "Donations should be rejected while paused");
+
+    // Should fail because the guardian may only add flags
+    let result = set_pause(banks_client, payer, recent_blockhash, &guardian, 0).await;
+    assert!(result.is_err(), "The guardian cannot resume the program");
+
+    set_pause(banks_client, payer, recent_blockhash, admin, 0)
+        .await
+        .expect("Failed to resume donations");
+
+    let result = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "", 2,
+    )
+    .await;
+    assert!(result.is_ok(), "Donations should resume: {:?}", result);
+}
+
+#[tokio::test]
+async fn test_pause_dog_management() {
+    let (mut env, [donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    // Should fail because the flag is unknown
+    let result = set_pause(banks_client, payer, recent_blockhash, admin, 1 << 7).await;
+    assert!(result.is_err(), "Unknown pause flags should be rejected");
+
+    set_pause(banks_client, payer, recent_blockhash, admin, PAUSE_DOG_MANAGEMENT | PAUSE_WITHDRAWALS)
+        .await
+        .expect("Failed to pause dog management");
+
+    // Should fail because dog management is paused
+    let result = add_dog(
+        banks_client, payer, recent_blockhash, admin, shelter,
+        "Bella", 0, 0, "https://example.com/bella.png", "Left at the shelter door", DogProfile::default(), "", [0; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Adding dogs should be rejected while paused");
+
+    let result = register_shelter(banks_client, payer, recent_blockhash, admin, "Second Chance", &Keypair::new().pubkey(), &admin.pubkey()).await;
+    assert!(result.is_err(), "Registering shelters should be rejected while paused");
+
+    // Donations are not affected
+    let result = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "", 1,
+    )
+    .await;
+    assert!(result.is_ok(), "Donations should not be paused: {:?}", result);
+}
+
+#[tokio::test]
+async fn test_pause_exempts_admin_and_moderation() {
+    let (mut env, [donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    let donation = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "Visit my spam site", 1,
+    )
+    .await
+    .expect("Failed to donate");
+
+    set_pause(banks_client, payer, recent_blockhash, admin, PAUSE_ALL)
+        .await
+        .expect("Failed to pause the program");
+
+    // Moderation keeps working so abuse can be hidden during an incident
+    let result = hide_donation_message(banks_client, payer, recent_blockhash, admin, false, &donation).await;
+    assert!(result.is_ok(), "Hiding messages should not be paused: {:?}", result);
+    let donation_account: Donation = fetch_account(banks_client, &donation).await;
+    assert!(donation_account.message_hidden, "Message should be hidden while paused");
+
+    // Admin configuration keeps working so the platform can be reconfigured
+    let result = set_withdraw_limit(banks_client, payer, recent_blockhash, admin, 1_000_000, 86_400).await;
+    assert!(result.is_ok(), "Admin setters should not be paused: {:?}", result);
+    let result = verify_shelter(banks_client, payer, recent_blockhash, admin, shelter, [7; 32]).await;
+    assert!(result.is_ok(), "Verifying shelters should not be paused: {:?}", result);
+
+    let platform: Platform = fetch_account(banks_client, &find_platform_pda().0).await;
+    assert_eq!(platform.paused, PAUSE_ALL, "Pause flags should be unchanged");
+    assert_eq!(platform.max_withdraw_per_period, 1_000_000, "Withdraw limit mismatch");
+}
+
+#[tokio::test]
+async fn test_pause_covers_transfers_and_replies() {
+    let (mut env, [donor, rescue_admin]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    let rescue = register_shelter(
+        banks_client, payer, recent_blockhash, admin, "Second Chance", &Keypair::new().pubkey(), &rescue_admin.pubkey(),
+    )
+    .await
+    .expect("Failed to register shelter");
+    propose_dog_transfer(banks_client, payer, recent_blockhash, admin, shelter, &rescue, dog)
+        .await
+        .expect("Failed to propose transfer");
+    let donation = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "Good boy", 1,
+    )
+    .await
+    .expect("Failed to donate");
+
+    set_pause(banks_client, payer, recent_blockhash, admin, PAUSE_DOG_MANAGEMENT | PAUSE_DONATIONS)
+        .await
+        .expect("Failed to pause the program");
+
+    // Should fail because dog management is paused
+    let result = cancel_dog_transfer(banks_client, payer, recent_blockhash, &rescue_admin, dog).await;
+    assert!(result.is_err(), "Cancelling transfers should be rejected while paused");
+
+    // Should fail because donations and their messages are paused
+    let result = reply_to_donation(banks_client, payer, recent_blockhash, admin, false, &donation, "Thank you!").await;
+    assert!(result.is_err(), "Replies should be rejected while donations are paused");
+
+    set_pause(banks_client, payer, recent_blockhash, admin, PAUSE_DOG_MANAGEMENT)
+        .await
+        .expect("Failed to resume donations");
+    let result = reply_to_donation(banks_client, payer, recent_blockhash, admin, false, &donation, "Thank you!").await;
+    assert!(result.is_ok(), "Replies only follow the donations flag: {:?}", result);
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use woofi::state::{Platform, Shelter, SpendingCategory};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_withdraw_limit_per_period() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, .. } = &mut env;
+    // The recipient starts empty, so the first payment has to cover its rent exemption
+    let recipient = Keypair::new().pubkey();
+
+    // Should fail because a limit needs a period
+    let result = set_withdraw_limit(banks_client, payer, recent_blockhash, admin, 3_000_000, 0).await;
+    assert!(result.is_err(), "A limit without a period should be rejected");
+
+    set_withdraw_limit(banks_client, payer, recent_blockhash, admin, 3_000_000, 86_400)
+        .await
+        .expect("Failed to set withdraw limit");
+
+    withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, None, &treasury.pubkey(), &recipient,
+        2_000_000, SpendingCategory::Food, [0; 32],
+    )
+    .await
+    .expect("Failed to withdraw within the limit");
+
+    let platform: Platform = fetch_account(banks_client, &find_platform_pda().0).await;
+    let shelter_account: Shelter = fetch_account(banks_client, shelter).await;
+    let clock: Clock = banks_client.get_sysvar().await.unwrap();
+    assert_eq!(shelter_account.remaining_allowance(&platform, clock.unix_timestamp), 1_000_000, "Remaining allowance mismatch");
+    assert_eq!(
+        shelter_account.remaining_allowance(&platform, shelter_account.period_start + 86_400),
+        3_000_000,
+        "Allowance should reset with the next period",
+    );
+
+    // Should fail because the withdrawal exceeds the remaining allowance
+    let result = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, None, &treasury.pubkey(), &recipient,
+        2_000_000, SpendingCategory::Food, [0; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Withdrawals above the period cap should be rejected");
+
+    let result = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, None, &treasury.pubkey(), &recipient,
+        1_000_000, SpendingCategory::Food, [0; 32],
+    )
+    .await;
+    assert!(result.is_ok(), "Failed to withdraw the remaining allowance: {:?}", result);
+}
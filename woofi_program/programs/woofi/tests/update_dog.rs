@@ -7,6 +7,7 @@ use {
 		woofi_ix_interface,
 	},
     solana_program_test::tokio,
+    woofi::state::DogProfile,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
     },
@@ -35,12 +36,12 @@ async fn update_dog_ix_success() {
 	// DATA
 	let image_url: String = Default::default();
 	let story: String = Default::default();
-	let needs_food: bool = Default::default();
-	let needs_toys: bool = Default::default();
-	let needs_medical: bool = Default::default();
-	let needs_shelter: bool = Default::default();
-	let needs_other: String = Default::default();
 	let active: bool = Default::default();
+	let profile: DogProfile = Default::default();
+	let intake_date: i64 = Default::default();
+	let birth_date: i64 = Default::default();
+	let metadata_uri: String = Default::default();
+	let metadata_hash: [u8; 32] = Default::default();
 
 	// KEYPAIR
 	let admin_keypair = Keypair::new();
@@ -50,6 +51,14 @@ async fn update_dog_ix_success() {
 	let admin_pubkey = admin_keypair.pubkey();
 	let dog_pubkey = dog_keypair.pubkey();
 
+	// PDA
+	let (platform_pda, _platform_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"platform",
+		],
+		&woofi::ID,
+	);
+
 	// ACCOUNT PROGRAM TEST SETUP
 	program_test.add_account(
 		admin_pubkey,
@@ -67,15 +76,16 @@ async fn update_dog_ix_success() {
 
 	let ix = woofi_ix_interface::update_dog_ix_setup(
 		&admin_keypair,
+		platform_pda,
 		dog_pubkey,
 		&image_url,
 		&story,
-		needs_food,
-		needs_toys,
-		needs_medical,
-		needs_shelter,
-		&needs_other,
 		active,
+		profile,
+		intake_date,
+		birth_date,
+		&metadata_uri,
+		metadata_hash,
 		recent_blockhash,
 	);
 
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use woofi::{state::{Platform, Shelter, TreasuryHistory}, PAUSE_WITHDRAWALS};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_update_treasury() {
+    let (mut env, [stranger, donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    let old_treasury = treasury.pubkey();
+    let platform_treasury = fetch_account::<Platform>(banks_client, &find_platform_pda().0).await.treasury;
+    let new_treasury = Keypair::new().pubkey();
+
+    // Should fail because only the shelter admin can change its treasury
+    let result = update_treasury(banks_client, payer, recent_blockhash, &stranger, shelter, &new_treasury).await;
+    assert!(result.is_err(), "Only the shelter admin can change the treasury");
+
+    // Should fail because the zero address cannot hold funds
+    let result = update_treasury(banks_client, payer, recent_blockhash, admin, shelter, &Pubkey::default()).await;
+    assert!(result.is_err(), "Invalid treasuries should be rejected");
+
+    // Should fail because withdrawals are paused
+    set_pause(banks_client, payer, recent_blockhash, admin, PAUSE_WITHDRAWALS)
+        .await
+        .expect("Failed to pause withdrawals");
+    let result = update_treasury(banks_client, payer, recent_blockhash, admin, shelter, &new_treasury).await;
+    assert!(result.is_err(), "Treasuries cannot be redirected while withdrawals are paused");
+    set_pause(banks_client, payer, recent_blockhash, admin, 0)
+        .await
+        .expect("Failed to resume withdrawals");
+
+    let result = update_treasury(banks_client, payer, recent_blockhash, admin, shelter, &new_treasury).await;
+    assert!(result.is_ok(), "Failed to update treasury: {:?}", result);
+
+    let shelter_account: Shelter = fetch_account(banks_client, shelter).await;
+    assert_eq!(shelter_account.treasury, new_treasury, "Treasury should apply without a timelock");
+    let platform: Platform = fetch_account(banks_client, &find_platform_pda().0).await;
+    assert_eq!(platform.treasury, platform_treasury, "Platform treasury should be untouched");
+
+    let history: TreasuryHistory = fetch_account(banks_client, &find_treasury_history_pda(shelter).0).await;
+    let changes: Vec<_> = history.changes().collect();
+    assert_eq!(changes.len(), 1, "History should hold one change");
+    assert_eq!(changes[0].treasury, old_treasury, "History should record the previous treasury");
+
+    // Donations now go to the new treasury
+    let result = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &old_treasury,
+        1_000_000, "", 1,
+    )
+    .await;
+    assert!(result.is_err(), "Donations to the previous treasury should be rejected");
+    let result = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &new_treasury,
+        1_000_000, "", 2,
+    )
+    .await;
+    assert!(result.is_ok(), "Failed to donate to the new treasury: {:?}", result);
+}
+
+#[tokio::test]
+async fn test_update_treasury_with_timelock() {
+    let mut context = setup_program_test().start_with_context().await;
+    let admin = Keypair::new();
+    let payer = context.payer.insecure_clone();
+    let recent_blockhash = context.last_blockhash;
+    airdrop(&mut context.banks_client, &payer, &recent_blockhash, &admin.pubkey(), 10_000_000_000).await;
+
+    create_platform(&mut context.banks_client, &payer, &recent_blockhash, &admin, &Keypair::new().pubkey())
+        .await
+        .expect("Failed to initialize platform");
+    let old_treasury = Keypair::new().pubkey();
+    let shelter = register_shelter(&mut context.banks_client, &payer, &recent_blockhash, &admin, "Happy Paws", &old_treasury, &admin.pubkey())
+        .await
+        .expect("Failed to register shelter");
+    set_treasury_timelock(&mut context.banks_client, &payer, &recent_blockhash, &admin, 3_600)
+        .await
+        .expect("Failed to set timelock");
+
+    // Should fail because the timelock can only be increased
+    let result = set_treasury_timelock(&mut context.banks_client, &payer, &recent_blockhash, &admin, 0).await;
+    assert!(result.is_err(), "The timelock cannot be lowered");
+
+    // The first call only proposes the change
+    let new_treasury = Keypair::new().pubkey();
+    update_treasury(&mut context.banks_client, &payer, &recent_blockhash, &admin, &shelter, &new_treasury)
+        .await
+        .expect("Failed to propose treasury");
+
+    let shelter_account: Shelter = fetch_account(&mut context.banks_client, &shelter).await;
+    assert_eq!(shelter_account.treasury, old_treasury, "Treasury should not change before the timelock");
+    let history: TreasuryHistory = fetch_account(&mut context.banks_client, &find_treasury_history_pda(&shelter).0).await;
+    assert_eq!(history.pending_treasury, new_treasury, "Pending treasury mismatch");
+
+    // Should fail because the timelock has not expired
+    let result = update_treasury(&mut context.banks_client, &payer, &recent_blockhash, &admin, &shelter, &new_treasury).await;
+    assert!(result.is_err(), "Treasury change should wait for the timelock");
+
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = history.pending_treasury_eta;
+    context.set_sysvar(&clock);
+
+    update_treasury(&mut context.banks_client, &payer, &recent_blockhash, &admin, &shelter, &new_treasury)
+        .await
+        .expect("Failed to apply treasury");
+
+    let shelter_account: Shelter = fetch_account(&mut context.banks_client, &shelter).await;
+    assert_eq!(shelter_account.treasury, new_treasury, "Treasury should apply after the timelock");
+    let history: TreasuryHistory = fetch_account(&mut context.banks_client, &find_treasury_history_pda(&shelter).0).await;
+    assert_eq!(history.pending_treasury, Pubkey::default(), "Pending treasury should be cleared");
+    assert_eq!(history.changes().next().unwrap().treasury, old_treasury, "History should record the previous treasury");
+}
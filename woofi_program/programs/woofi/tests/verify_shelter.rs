@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+use woofi::state::{Donation, Shelter};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_verify_and_revoke_shelter() {
+    let (mut env, [stranger]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, shelter, .. } = &mut env;
+
+    // Should fail because only the platform admin can verify shelters
+    let result = verify_shelter(banks_client, payer, recent_blockhash, &stranger, shelter, [7; 32]).await;
+    assert!(result.is_err(), "Only the platform admin can verify shelters");
+
+    let result = verify_shelter(banks_client, payer, recent_blockhash, admin, shelter, [7; 32]).await;
+    assert!(result.is_ok(), "Failed to verify shelter: {:?}", result);
+
+    let shelter_account: Shelter = fetch_account(banks_client, shelter).await;
+    assert!(shelter_account.verified, "Shelter should be verified");
+    assert_eq!(shelter_account.verification_hash, [7; 32], "Verification hash mismatch");
+    assert_ne!(shelter_account.verified_at, 0, "Verification time should be recorded");
+
+    let result = revoke_shelter(banks_client, payer, recent_blockhash, admin, shelter).await;
+    assert!(result.is_ok(), "Failed to revoke shelter: {:?}", result);
+
+    let shelter_account: Shelter = fetch_account(banks_client, shelter).await;
+    assert!(!shelter_account.verified, "Shelter should no longer be verified");
+    assert_eq!(shelter_account.verification_hash, [0; 32], "Verification hash should be cleared");
+}
+
+#[tokio::test]
+async fn test_donate_requires_verified_shelter() {
+    let (mut env, [donor]) = start_with_wallets(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+
+    // Without the policy, donations to unverified shelters are flagged
+    let donation = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "", 1,
+    )
+    .await
+    .expect("Failed to donate");
+    let donation_account: Donation = fetch_account(banks_client, &donation).await;
+    assert!(!donation_account.shelter_verified, "Donation should be flagged as unverified");
+
+    set_shelter_policy(banks_client, payer, recent_blockhash, admin, true)
+        .await
+        .expect("Failed to set shelter policy");
+
+    // Should fail because the shelter is not verified
+    let result = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "", 2,
+    )
+    .await;
+    assert!(result.is_err(), "Donations to unverified shelters should be rejected");
+
+    verify_shelter(banks_client, payer, recent_blockhash, admin, shelter, [7; 32])
+        .await
+        .expect("Failed to verify shelter");
+
+    let donation = donate(
+        banks_client, payer, recent_blockhash, &donor, shelter, dog, &treasury.pubkey(),
+        1_000_000, "", 3,
+    )
+    .await
+    .expect("Failed to donate to verified shelter");
+    let donation_account: Donation = fetch_account(banks_client, &donation).await;
+    assert!(donation_account.shelter_verified, "Donation should record the verified shelter");
+}
@@ -7,6 +7,7 @@ use {
 		woofi_ix_interface,
 	},
     solana_program_test::tokio,
+    woofi::state::SpendingCategory,
     solana_sdk::{
         account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
     },
@@ -34,15 +35,22 @@ async fn withdraw_funds_ix_success() {
 
 	// DATA
 	let amount: u64 = Default::default();
+	let category: SpendingCategory = SpendingCategory::Food;
+	let invoice_hash: [u8; 32] = Default::default();
 
 	// KEYPAIR
 	let admin_keypair = Keypair::new();
+	let shelter_keypair = Keypair::new();
 
 	// PUBKEY
 	let admin_pubkey = admin_keypair.pubkey();
+	let shelter_pubkey = shelter_keypair.pubkey();
 	let treasury_pubkey = Pubkey::new_unique();
 	let recipient_pubkey = Pubkey::new_unique();
 
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
 	// PDA
 	let (platform_pda, _platform_pda_bump) = Pubkey::find_program_address(
 		&[
@@ -51,6 +59,15 @@ async fn withdraw_funds_ix_success() {
 		&woofi::ID,
 	);
 
+	let (withdrawal_pda, _withdrawal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"withdrawal",
+			shelter_pubkey.as_ref(),
+			0u64.to_le_bytes().as_ref(),
+		],
+		&woofi::ID,
+	);
+
 	// ACCOUNT PROGRAM TEST SETUP
 	program_test.add_account(
 		admin_pubkey,
@@ -69,9 +86,16 @@ async fn withdraw_funds_ix_success() {
 	let ix = woofi_ix_interface::withdraw_funds_ix_setup(
 		&admin_keypair,
 		platform_pda,
+		shelter_pubkey,
+		None,
 		treasury_pubkey,
 		recipient_pubkey,
+		None,
+		withdrawal_pda,
+		system_program_pubkey,
 		amount,
+		category,
+		invoice_hash,
 		recent_blockhash,
 	);
 
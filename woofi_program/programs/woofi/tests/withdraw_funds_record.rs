@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use woofi::state::{Dog, DogProfile, Shelter, SpendingCategory, Withdrawal};
+
+mod common;
+use common::*;
+
+#[tokio::test]
+async fn test_withdrawal_records() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, dog } = &mut env;
+    // The recipient starts empty, so the first payment has to cover its rent exemption
+    let recipient = Keypair::new().pubkey();
+
+    let withdrawal = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, Some(dog), &treasury.pubkey(), &recipient,
+        1_000_000, SpendingCategory::Medical, [9; 32],
+    )
+    .await
+    .expect("Failed to withdraw");
+
+    let record: Withdrawal = fetch_account(banks_client, &withdrawal).await;
+    assert_eq!(record.shelter, *shelter, "Shelter mismatch");
+    assert_eq!(record.dog, Some(*dog), "Linked dog mismatch");
+    assert_eq!(record.amount, 1_000_000, "Amount mismatch");
+    assert_eq!(record.recipient, recipient, "Recipient mismatch");
+    assert_eq!(record.category, SpendingCategory::Medical, "Category mismatch");
+    assert_eq!(record.invoice_hash, [9; 32], "Invoice hash mismatch");
+    assert_eq!(record.index, 0, "First withdrawal should have index 0");
+
+    let dog_account: Dog = fetch_account(banks_client, dog).await;
+    assert_eq!(dog_account.total_withdrawn, 1_000_000, "Dog spending mismatch");
+
+    // Withdrawals without a dog get the next index
+    let withdrawal = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, None, &treasury.pubkey(), &recipient,
+        50_000, SpendingCategory::Shelter, [0; 32],
+    )
+    .await
+    .expect("Failed to withdraw");
+
+    let record: Withdrawal = fetch_account(banks_client, &withdrawal).await;
+    assert_eq!(record.dog, None, "No dog should be linked");
+    assert_eq!(record.index, 1, "Second withdrawal should have index 1");
+
+    let shelter_account: Shelter = fetch_account(banks_client, shelter).await;
+    assert_eq!(shelter_account.withdrawal_count, 2, "Withdrawal count mismatch");
+}
+
+#[tokio::test]
+async fn test_withdrawal_dog_from_other_shelter() {
+    let mut env = start_with_dog(setup_program_test(), "Rex").await;
+    let TestEnv { banks_client, payer, recent_blockhash, admin, treasury, shelter, .. } = &mut env;
+
+    let other_shelter = register_shelter(banks_client, payer, recent_blockhash, admin, "Second Chance", &Keypair::new().pubkey(), &admin.pubkey())
+        .await
+        .expect("Failed to register second shelter");
+    add_dog(
+        banks_client, payer, recent_blockhash, admin, &other_shelter,
+        "Bella", 0, 0, "https://example.com/bella.png", "Left at the shelter door", DogProfile::default(), "", [0; 32],
+    )
+    .await
+    .expect("Failed to add dog");
+
+    // Should fail because the dog belongs to another shelter
+    let result = withdraw_funds(
+        banks_client, payer, recent_blockhash, admin, shelter, Some(&find_dog_pda(&other_shelter, "Bella").0),
+        &treasury.pubkey(), &Keypair::new().pubkey(), 100_000, SpendingCategory::Food, [0; 32],
+    )
+    .await;
+    assert!(result.is_err(), "Dogs of other shelters cannot be linked");
+}